@@ -1,4 +1,9 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     tonic_build::compile_protos("proto/auction.proto")?;
+    // Mensajes estándar de google.rpc para los detalles de error
+    tonic_build::configure()
+        .build_client(false)
+        .build_server(false)
+        .compile(&["proto/google/rpc/status.proto", "proto/google/rpc/error_details.proto"], &["proto"])?;
     Ok(())
-}
\ No newline at end of file
+}
@@ -2,6 +2,20 @@ pub use sea_orm_migration::prelude::*;
 
 mod m20250619_044136_create_auction_table;
 mod m20250621_220711_create_bid_table;
+mod m20261017_100000_create_auction_result_table;
+mod m20261017_110000_add_reserve_price;
+mod m20261017_120000_create_proxy_bid_table;
+mod m20261017_130000_add_soft_close_settings;
+mod m20261017_140000_create_auction_closure_table;
+mod m20261017_150000_add_bid_retraction;
+mod m20261017_160000_add_auction_deleted_at;
+mod m20261017_170000_create_idempotency_key_table;
+mod m20261017_180000_add_auction_version;
+mod m20261017_190000_use_timestamptz_and_native_enums;
+mod m20261017_200000_allow_cancelled_proxy_bids;
+mod m20261017_210000_allow_retracted_proxy_bids;
+mod m20261017_220000_use_native_enum_for_result_currency;
+mod m20261017_230000_use_native_enum_for_closure_action;
 
 pub struct Migrator;
 
@@ -11,6 +25,20 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20250619_044136_create_auction_table::Migration),
             Box::new(m20250621_220711_create_bid_table::Migration),
+            Box::new(m20261017_100000_create_auction_result_table::Migration),
+            Box::new(m20261017_110000_add_reserve_price::Migration),
+            Box::new(m20261017_120000_create_proxy_bid_table::Migration),
+            Box::new(m20261017_130000_add_soft_close_settings::Migration),
+            Box::new(m20261017_140000_create_auction_closure_table::Migration),
+            Box::new(m20261017_150000_add_bid_retraction::Migration),
+            Box::new(m20261017_160000_add_auction_deleted_at::Migration),
+            Box::new(m20261017_170000_create_idempotency_key_table::Migration),
+            Box::new(m20261017_180000_add_auction_version::Migration),
+            Box::new(m20261017_190000_use_timestamptz_and_native_enums::Migration),
+            Box::new(m20261017_200000_allow_cancelled_proxy_bids::Migration),
+            Box::new(m20261017_210000_allow_retracted_proxy_bids::Migration),
+            Box::new(m20261017_220000_use_native_enum_for_result_currency::Migration),
+            Box::new(m20261017_230000_use_native_enum_for_closure_action::Migration),
         ]
     }
 }
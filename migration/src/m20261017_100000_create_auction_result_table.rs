@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuctionResult::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(AuctionResult::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(AuctionResult::AuctionId).uuid().not_null().unique_key())
+                    .col(ColumnDef::new(AuctionResult::WinningBidId).uuid().null())
+                    .col(ColumnDef::new(AuctionResult::WinnerUserId).string().null())
+                    .col(ColumnDef::new(AuctionResult::FinalPrice).decimal().null())
+                    .col(ColumnDef::new(AuctionResult::Currency).string().not_null())
+                    .col(ColumnDef::new(AuctionResult::ClosedAt).date_time().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_auction_result_auction")
+                            .from(AuctionResult::Table, AuctionResult::AuctionId)
+                            .to(Auction::Table, Auction::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_auction_result_bid")
+                            .from(AuctionResult::Table, AuctionResult::WinningBidId)
+                            .to(Bid::Table, Bid::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuctionResult::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum AuctionResult {
+    Table,
+    Id,
+    AuctionId,
+    WinningBidId,
+    WinnerUserId,
+    FinalPrice,
+    Currency,
+    ClosedAt,
+}
+
+#[derive(Iden)]
+enum Auction {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Bid {
+    Table,
+    Id,
+}
@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Auction::Table)
+                    .add_column(ColumnDef::new(Auction::ReservePrice).decimal().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuctionResult::Table)
+                    .add_column(
+                        ColumnDef::new(AuctionResult::ReserveMet)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuctionResult::Table)
+                    .drop_column(AuctionResult::ReserveMet)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Auction::Table)
+                    .drop_column(Auction::ReservePrice)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Auction {
+    Table,
+    ReservePrice,
+}
+
+#[derive(Iden)]
+enum AuctionResult {
+    Table,
+    ReserveMet,
+}
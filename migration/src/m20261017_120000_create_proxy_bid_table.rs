@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProxyBid::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ProxyBid::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(ProxyBid::AuctionId).uuid().not_null())
+                    .col(ColumnDef::new(ProxyBid::UserId).string().not_null())
+                    .col(ColumnDef::new(ProxyBid::MaxAmount).decimal().not_null())
+                    .col(ColumnDef::new(ProxyBid::CreatedAt).date_time().not_null())
+                    .col(
+                        ColumnDef::new(ProxyBid::Status)
+                            .string()
+                            .not_null()
+                            .default("active")
+                            .check(Expr::col(ProxyBid::Status).is_in(["active", "exhausted"]))
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_proxy_bid_auction")
+                            .from(ProxyBid::Table, ProxyBid::AuctionId)
+                            .to(Auction::Table, Auction::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Un único máximo por postor y subasta
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_proxy_bid_auction_user")
+                    .table(ProxyBid::Table)
+                    .col(ProxyBid::AuctionId)
+                    .col(ProxyBid::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProxyBid::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum ProxyBid {
+    Table,
+    Id,
+    AuctionId,
+    UserId,
+    MaxAmount,
+    CreatedAt,
+    Status,
+}
+
+#[derive(Iden)]
+enum Auction {
+    Table,
+    Id,
+}
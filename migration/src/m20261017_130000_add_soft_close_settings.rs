@@ -0,0 +1,44 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Auction::Table)
+                    .add_column(ColumnDef::new(Auction::ExtensionWindowSecs).integer().not_null().default(0))
+                    .add_column(ColumnDef::new(Auction::ExtensionSecs).integer().not_null().default(0))
+                    .add_column(ColumnDef::new(Auction::MaxExtensions).integer().not_null().default(0))
+                    .add_column(ColumnDef::new(Auction::ExtensionsCount).integer().not_null().default(0))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Auction::Table)
+                    .drop_column(Auction::ExtensionWindowSecs)
+                    .drop_column(Auction::ExtensionSecs)
+                    .drop_column(Auction::MaxExtensions)
+                    .drop_column(Auction::ExtensionsCount)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Auction {
+    Table,
+    ExtensionWindowSecs,
+    ExtensionSecs,
+    MaxExtensions,
+    ExtensionsCount,
+}
@@ -0,0 +1,54 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuctionClosure::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(AuctionClosure::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(AuctionClosure::AuctionId).uuid().not_null().unique_key())
+                    .col(ColumnDef::new(AuctionClosure::Action).string().not_null())
+                    .col(ColumnDef::new(AuctionClosure::ActorId).string().not_null())
+                    .col(ColumnDef::new(AuctionClosure::Reason).string().not_null())
+                    .col(ColumnDef::new(AuctionClosure::ClosedAt).date_time().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_auction_closure_auction")
+                            .from(AuctionClosure::Table, AuctionClosure::AuctionId)
+                            .to(Auction::Table, Auction::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuctionClosure::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum AuctionClosure {
+    Table,
+    Id,
+    AuctionId,
+    Action,
+    ActorId,
+    Reason,
+    ClosedAt,
+}
+
+#[derive(Iden)]
+enum Auction {
+    Table,
+    Id,
+}
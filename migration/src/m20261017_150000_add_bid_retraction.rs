@@ -0,0 +1,38 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Bid::Table)
+                    .add_column(ColumnDef::new(Bid::RetractedAt).date_time().null())
+                    .add_column(ColumnDef::new(Bid::RetractionReason).string().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Bid::Table)
+                    .drop_column(Bid::RetractedAt)
+                    .drop_column(Bid::RetractionReason)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Bid {
+    Table,
+    RetractedAt,
+    RetractionReason,
+}
@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(IdempotencyKey::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(IdempotencyKey::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(IdempotencyKey::UserId).string().not_null())
+                    .col(ColumnDef::new(IdempotencyKey::Operation).string().not_null())
+                    .col(ColumnDef::new(IdempotencyKey::Key).string_len(255).not_null())
+                    .col(ColumnDef::new(IdempotencyKey::Request).binary().not_null())
+                    .col(ColumnDef::new(IdempotencyKey::Response).binary().not_null())
+                    .col(ColumnDef::new(IdempotencyKey::CreatedAt).date_time().not_null())
+                    .col(ColumnDef::new(IdempotencyKey::ExpiresAt).date_time().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Una clave identifica una sola operación por usuario
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_idempotency_key_user_operation_key")
+                    .table(IdempotencyKey::Table)
+                    .col(IdempotencyKey::UserId)
+                    .col(IdempotencyKey::Operation)
+                    .col(IdempotencyKey::Key)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_idempotency_key_expires_at")
+                    .table(IdempotencyKey::Table)
+                    .col(IdempotencyKey::ExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(IdempotencyKey::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum IdempotencyKey {
+    Table,
+    Id,
+    UserId,
+    Operation,
+    Key,
+    Request,
+    Response,
+    CreatedAt,
+    ExpiresAt,
+}
@@ -0,0 +1,112 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, DbBackend};
+use sea_query::extension::postgres::Type;
+
+// Columnas de fecha guardadas hasta ahora como TIMESTAMP sin zona horaria.
+// Los valores siempre se escribieron en UTC.
+const TIME_COLUMNS: [(&str, &str); 10] = [
+    ("auction", "start_time"),
+    ("auction", "end_time"),
+    ("auction", "deleted_at"),
+    ("bid", "created_at"),
+    ("bid", "retracted_at"),
+    ("proxy_bid", "created_at"),
+    ("auction_result", "closed_at"),
+    ("auction_closure", "closed_at"),
+    ("idempotency_key", "created_at"),
+    ("idempotency_key", "expires_at"),
+];
+
+const STATUSES: [&str; 4] = ["pending", "active", "completed", "cancelled"];
+const CURRENCIES: [&str; 6] = ["USD", "EUR", "CLP", "ARS", "BRL", "MXN"];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Solo aplica en Postgres: SQLite no tiene tipos enum ni zona horaria en sus columnas
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        let db = manager.get_connection();
+
+        for (table, column) in TIME_COLUMNS {
+            db.execute_unprepared(&format!(
+                "ALTER TABLE {table} ALTER COLUMN {column} TYPE TIMESTAMPTZ USING {column} AT TIME ZONE 'UTC'"
+            ))
+            .await?;
+        }
+
+        manager
+            .create_type(Type::create().as_enum(AuctionStatus::Enum).values(STATUSES.map(Alias::new)).to_owned())
+            .await?;
+        manager
+            .create_type(Type::create().as_enum(AuctionCurrency::Enum).values(CURRENCIES.map(Alias::new)).to_owned())
+            .await?;
+
+        // Los CHECK de la tabla original quedan cubiertos por el tipo enum
+        db.execute_unprepared(
+            "ALTER TABLE auction
+                DROP CONSTRAINT IF EXISTS auction_status_check,
+                DROP CONSTRAINT IF EXISTS auction_currency_check,
+                ALTER COLUMN status DROP DEFAULT,
+                ALTER COLUMN currency DROP DEFAULT,
+                ALTER COLUMN status TYPE auction_status USING status::auction_status,
+                ALTER COLUMN currency TYPE auction_currency USING currency::auction_currency,
+                ALTER COLUMN status SET DEFAULT 'pending',
+                ALTER COLUMN currency SET DEFAULT 'USD'",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        let db = manager.get_connection();
+
+        db.execute_unprepared(&format!(
+            "ALTER TABLE auction
+                ALTER COLUMN status DROP DEFAULT,
+                ALTER COLUMN currency DROP DEFAULT,
+                ALTER COLUMN status TYPE VARCHAR USING status::text,
+                ALTER COLUMN currency TYPE VARCHAR USING currency::text,
+                ALTER COLUMN status SET DEFAULT 'pending',
+                ALTER COLUMN currency SET DEFAULT 'USD',
+                ADD CONSTRAINT auction_status_check CHECK (status IN ({})),
+                ADD CONSTRAINT auction_currency_check CHECK (currency IN ({}))",
+            quoted(&STATUSES),
+            quoted(&CURRENCIES),
+        ))
+        .await?;
+        manager.drop_type(Type::drop().name(AuctionStatus::Enum).to_owned()).await?;
+        manager.drop_type(Type::drop().name(AuctionCurrency::Enum).to_owned()).await?;
+
+        for (table, column) in TIME_COLUMNS {
+            db.execute_unprepared(&format!(
+                "ALTER TABLE {table} ALTER COLUMN {column} TYPE TIMESTAMP USING {column} AT TIME ZONE 'UTC'"
+            ))
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+fn quoted(values: &[&str]) -> String {
+    values.iter().map(|v| format!("'{}'", v)).collect::<Vec<_>>().join(", ")
+}
+
+#[derive(Iden)]
+enum AuctionStatus {
+    #[iden = "auction_status"]
+    Enum,
+}
+
+#[derive(Iden)]
+enum AuctionCurrency {
+    #[iden = "auction_currency"]
+    Enum,
+}
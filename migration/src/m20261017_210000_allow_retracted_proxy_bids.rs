@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, DbBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Al retractar una puja el máximo automático activo del postor queda como "retracted".
+    // Solo aplica en Postgres: SQLite no permite modificar un CHECK existente.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE proxy_bid
+                    DROP CONSTRAINT IF EXISTS proxy_bid_status_check,
+                    ADD CONSTRAINT proxy_bid_status_check
+                        CHECK (status IN ('active', 'exhausted', 'cancelled', 'retracted'))",
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        let db = manager.get_connection();
+        db.execute_unprepared("UPDATE proxy_bid SET status = 'exhausted' WHERE status = 'retracted'")
+            .await?;
+        db.execute_unprepared(
+            "ALTER TABLE proxy_bid
+                DROP CONSTRAINT IF EXISTS proxy_bid_status_check,
+                ADD CONSTRAINT proxy_bid_status_check CHECK (status IN ('active', 'exhausted', 'cancelled'))",
+        )
+        .await?;
+        Ok(())
+    }
+}
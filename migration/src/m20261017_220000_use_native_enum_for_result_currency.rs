@@ -0,0 +1,35 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, DbBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // La moneda del resultado usa el mismo tipo enum que la de la subasta.
+    // Solo aplica en Postgres: SQLite no tiene tipos enum.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE auction_result
+                    ALTER COLUMN currency TYPE auction_currency USING currency::auction_currency",
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE auction_result ALTER COLUMN currency TYPE VARCHAR USING currency::text")
+            .await?;
+        Ok(())
+    }
+}
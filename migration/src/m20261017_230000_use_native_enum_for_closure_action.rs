@@ -0,0 +1,35 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, DbBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // La acción de un cierre es el estado final de la subasta (completed o
+    // cancelled) y usa el mismo tipo enum. Solo aplica en Postgres.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE auction_closure
+                    ALTER COLUMN action TYPE auction_status USING action::auction_status",
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE auction_closure ALTER COLUMN action TYPE VARCHAR USING action::text")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+use std::sync::Arc;
+
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+use crate::error::AppError;
+
+// Identidad del llamador verificada a partir del token; el interceptor la deja
+// en las extensiones de la solicitud
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identity {
+    pub user_id: String,
+    pub roles: Vec<String>,
+}
+
+impl Identity {
+    pub fn is_admin(&self) -> bool {
+        self.roles.iter().any(|r| r == crate::policy::ADMIN_ROLE)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Claims {
+    sub: String,
+    #[serde(default)]
+    roles: Vec<String>,
+}
+
+// Verificador de tokens JWT firmados con HS256 (secreto compartido) o RS256 (clave pública)
+pub struct JwtVerifier {
+    key: DecodingKey,
+    validation: Validation,
+}
+
+impl JwtVerifier {
+    pub fn hs256(secret: &[u8]) -> Self {
+        JwtVerifier::new(DecodingKey::from_secret(secret), Algorithm::HS256)
+    }
+
+    pub fn rs256_pem(public_key: &[u8]) -> Result<Self, jsonwebtoken::errors::Error> {
+        Ok(JwtVerifier::new(DecodingKey::from_rsa_pem(public_key)?, Algorithm::RS256))
+    }
+
+    fn new(key: DecodingKey, algorithm: Algorithm) -> Self {
+        let mut validation = Validation::new(algorithm);
+        validation.validate_aud = false;
+        JwtVerifier { key, validation }
+    }
+
+    pub fn with_issuer(mut self, issuer: &str) -> Self {
+        self.validation.set_issuer(&[issuer]);
+        self
+    }
+
+    pub fn with_audience(mut self, audience: &str) -> Self {
+        self.validation.set_audience(&[audience]);
+        self.validation.validate_aud = true;
+        self
+    }
+
+    // Configuración desde el entorno:
+    // - JWT_ALGORITHM: HS256 (por defecto) o RS256
+    // - HS256: JWT_SECRET o JWT_SECRET_FILE
+    // - RS256: JWT_PUBLIC_KEY_FILE con la clave pública en PEM
+    // - JWT_ISSUER y JWT_AUDIENCE opcionales
+    pub fn from_env() -> Result<Self, String> {
+        let algorithm = std::env::var("JWT_ALGORITHM").unwrap_or_else(|_| "HS256".to_string());
+        let verifier = match algorithm.to_uppercase().as_str() {
+            "HS256" => {
+                let secret = match (std::env::var("JWT_SECRET"), std::env::var("JWT_SECRET_FILE")) {
+                    (Ok(secret), _) => secret.into_bytes(),
+                    (Err(_), Ok(path)) => std::fs::read(&path)
+                        .map_err(|e| format!("No se pudo leer JWT_SECRET_FILE '{}': {}", path, e))?,
+                    _ => return Err("Debe definir JWT_SECRET o JWT_SECRET_FILE".to_string()),
+                };
+                if secret.is_empty() {
+                    return Err("El secreto JWT no puede estar vacío".to_string());
+                }
+                JwtVerifier::hs256(&secret)
+            },
+            "RS256" => {
+                let path = std::env::var("JWT_PUBLIC_KEY_FILE")
+                    .map_err(|_| "Debe definir JWT_PUBLIC_KEY_FILE para RS256".to_string())?;
+                let pem = std::fs::read(&path)
+                    .map_err(|e| format!("No se pudo leer JWT_PUBLIC_KEY_FILE '{}': {}", path, e))?;
+                JwtVerifier::rs256_pem(&pem).map_err(|e| format!("Clave pública RS256 inválida: {}", e))?
+            },
+            other => return Err(format!("JWT_ALGORITHM no soportado: {}", other)),
+        };
+        let verifier = match std::env::var("JWT_ISSUER") {
+            Ok(issuer) if !issuer.is_empty() => verifier.with_issuer(&issuer),
+            _ => verifier,
+        };
+        Ok(match std::env::var("JWT_AUDIENCE") {
+            Ok(audience) if !audience.is_empty() => verifier.with_audience(&audience),
+            _ => verifier,
+        })
+    }
+
+    pub fn verify(&self, token: &str) -> Result<Identity, AppError> {
+        let data = jsonwebtoken::decode::<Claims>(token, &self.key, &self.validation).map_err(|e| {
+            log::warn!("Token rechazado: {}", e);
+            AppError::InvalidToken
+        })?;
+        if data.claims.sub.trim().is_empty() {
+            return Err(AppError::InvalidToken);
+        }
+        Ok(Identity { user_id: data.claims.sub, roles: data.claims.roles })
+    }
+}
+
+// Interceptor que exige "authorization: Bearer <jwt>" en cada llamada
+#[derive(Clone)]
+pub struct AuthInterceptor {
+    verifier: Arc<JwtVerifier>,
+}
+
+impl AuthInterceptor {
+    pub fn new(verifier: JwtVerifier) -> Self {
+        AuthInterceptor { verifier: Arc::new(verifier) }
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let header = request
+            .metadata()
+            .get("authorization")
+            .and_then(|v| v.to_str().ok())
+            .ok_or(AppError::MissingCredentials)?;
+        let token = header
+            .strip_prefix("Bearer ")
+            .or_else(|| header.strip_prefix("bearer "))
+            .ok_or(AppError::MissingCredentials)?;
+        let identity = self.verifier.verify(token.trim())?;
+        request.extensions_mut().insert(identity);
+        Ok(request)
+    }
+}
+
+// Identidad verificada de la solicitud
+pub fn caller<T>(request: &Request<T>) -> Result<Identity, AppError> {
+    request.extensions().get::<Identity>().cloned().ok_or(AppError::MissingCredentials)
+}
+
+// Resuelve el user_id efectivo: siempre el del token. Un user_id en el mensaje
+// solo se acepta si coincide, para no ocultar clientes que suplantan a otro usuario.
+pub fn resolve_user_id(identity: &Identity, payload_user_id: &str, field: &'static str) -> Result<String, AppError> {
+    if !payload_user_id.is_empty() && payload_user_id != identity.user_id {
+        return Err(AppError::CallerMismatch { field });
+    }
+    Ok(identity.user_id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{EncodingKey, Header};
+    use serde::Serialize;
+
+    const PRIVATE_KEY: &[u8] = include_bytes!("../tests/fixtures/jwt_rs256_private.pem");
+    const PUBLIC_KEY: &[u8] = include_bytes!("../tests/fixtures/jwt_rs256_public.pem");
+
+    #[derive(Serialize)]
+    struct TestClaims<'a> {
+        sub: &'a str,
+        exp: i64,
+        iss: &'a str,
+    }
+
+    fn claims(sub: &str, expires_in: i64) -> TestClaims<'_> {
+        TestClaims { sub, exp: chrono::Utc::now().timestamp() + expires_in, iss: "auction-tests" }
+    }
+
+    fn hs256_token(secret: &[u8], sub: &str, expires_in: i64) -> String {
+        jsonwebtoken::encode(&Header::new(Algorithm::HS256), &claims(sub, expires_in), &EncodingKey::from_secret(secret))
+            .unwrap()
+    }
+
+    fn intercept(interceptor: &mut AuthInterceptor, authorization: Option<&str>) -> Result<Identity, Status> {
+        let mut request = Request::new(());
+        if let Some(value) = authorization {
+            request.metadata_mut().insert("authorization", value.parse().unwrap());
+        }
+        let request = interceptor.call(request)?;
+        Ok(caller(&request).unwrap())
+    }
+
+    #[test]
+    fn test_hs256_token_sets_identity() {
+        let mut interceptor = AuthInterceptor::new(JwtVerifier::hs256(b"secreto"));
+        let token = hs256_token(b"secreto", "alice", 600);
+        let identity = intercept(&mut interceptor, Some(&format!("Bearer {}", token))).unwrap();
+        assert_eq!(identity.user_id, "alice");
+    }
+
+    #[test]
+    fn test_rs256_token_sets_identity() {
+        let verifier = JwtVerifier::rs256_pem(PUBLIC_KEY).unwrap().with_issuer("auction-tests");
+        let mut interceptor = AuthInterceptor::new(verifier);
+        let token = jsonwebtoken::encode(
+            &Header::new(Algorithm::RS256),
+            &claims("bob", 600),
+            &EncodingKey::from_rsa_pem(PRIVATE_KEY).unwrap(),
+        )
+        .unwrap();
+        let identity = intercept(&mut interceptor, Some(&format!("Bearer {}", token))).unwrap();
+        assert_eq!(identity.user_id, "bob");
+    }
+
+    #[test]
+    fn test_rejects_missing_invalid_and_expired_tokens() {
+        let mut interceptor = AuthInterceptor::new(JwtVerifier::hs256(b"secreto"));
+        let unauthenticated = |result: Result<Identity, Status>| result.unwrap_err().code() == tonic::Code::Unauthenticated;
+
+        assert!(unauthenticated(intercept(&mut interceptor, None)));
+        assert!(unauthenticated(intercept(&mut interceptor, Some("Basic abc"))));
+        let forged = hs256_token(b"otro secreto", "alice", 600);
+        assert!(unauthenticated(intercept(&mut interceptor, Some(&format!("Bearer {}", forged)))));
+        let expired = hs256_token(b"secreto", "alice", -600);
+        assert!(unauthenticated(intercept(&mut interceptor, Some(&format!("Bearer {}", expired)))));
+    }
+
+    #[test]
+    fn test_payload_user_id_must_match_token() {
+        let identity = Identity { user_id: "alice".to_string(), roles: Vec::new() };
+        assert_eq!(resolve_user_id(&identity, "", "user_id").unwrap(), "alice");
+        assert_eq!(resolve_user_id(&identity, "alice", "user_id").unwrap(), "alice");
+        assert!(matches!(resolve_user_id(&identity, "bob", "user_id"), Err(AppError::CallerMismatch { .. })));
+    }
+}
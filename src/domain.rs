@@ -0,0 +1,247 @@
+use sea_orm::entity::prelude::*;
+
+use crate::error::AppError;
+
+// Estados de una subasta. En Postgres se guardan con el tipo enum auction_status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "auction_status")]
+pub enum AuctionStatus {
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    #[sea_orm(string_value = "active")]
+    Active,
+    #[sea_orm(string_value = "completed")]
+    Completed,
+    #[sea_orm(string_value = "cancelled")]
+    Cancelled,
+}
+
+impl AuctionStatus {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            AuctionStatus::Pending => "pending",
+            AuctionStatus::Active => "active",
+            AuctionStatus::Completed => "completed",
+            AuctionStatus::Cancelled => "cancelled",
+        }
+    }
+
+    pub(crate) fn from_str(status: &str) -> Result<Self, AppError> {
+        match status.to_lowercase().as_str() {
+            "pending" => Ok(AuctionStatus::Pending),
+            "active" => Ok(AuctionStatus::Active),
+            "completed" => Ok(AuctionStatus::Completed),
+            "cancelled" => Ok(AuctionStatus::Cancelled),
+            _ => Err(AppError::InvalidStatus { value: status.to_string() }),
+        }
+    }
+
+    pub(crate) fn all_valid_statuses() -> Vec<&'static str> {
+        vec!["pending", "active", "completed", "cancelled"]
+    }
+
+    // Tabla de transiciones: completed y cancelled son estados finales
+    pub(crate) fn allowed_transitions(&self) -> &'static [AuctionStatus] {
+        match self {
+            AuctionStatus::Pending => &[AuctionStatus::Active, AuctionStatus::Cancelled],
+            AuctionStatus::Active => &[AuctionStatus::Completed, AuctionStatus::Cancelled],
+            AuctionStatus::Completed | AuctionStatus::Cancelled => &[],
+        }
+    }
+
+    pub(crate) fn can_transition_to(&self, next: &AuctionStatus) -> bool {
+        self.allowed_transitions().contains(next)
+    }
+
+    // Valida el paso de `self` a `next`; mantener el mismo estado no es una transición
+    pub(crate) fn check_transition(&self, next: &AuctionStatus) -> Result<(), AppError> {
+        if self == next || self.can_transition_to(next) {
+            return Ok(());
+        }
+        Err(AppError::InvalidStatusTransition {
+            from: self.as_str(),
+            to: next.as_str(),
+            allowed: self.allowed_transitions().iter().map(|s| s.as_str()).collect(),
+        })
+    }
+
+    pub(crate) fn is_final(&self) -> bool {
+        matches!(self, AuctionStatus::Completed | AuctionStatus::Cancelled)
+    }
+}
+
+// Monedas válidas (códigos ISO 4217). En Postgres se guardan con el tipo enum auction_currency.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "auction_currency")]
+pub enum AuctionCurrency {
+    #[sea_orm(string_value = "USD")]
+    USD,
+    #[sea_orm(string_value = "EUR")]
+    EUR,
+    #[sea_orm(string_value = "CLP")]
+    CLP,
+    #[sea_orm(string_value = "ARS")]
+    ARS,
+    #[sea_orm(string_value = "BRL")]
+    BRL,
+    #[sea_orm(string_value = "MXN")]
+    MXN,
+}
+
+impl AuctionCurrency {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            AuctionCurrency::USD => "USD",
+            AuctionCurrency::EUR => "EUR",
+            AuctionCurrency::CLP => "CLP",
+            AuctionCurrency::ARS => "ARS",
+            AuctionCurrency::BRL => "BRL",
+            AuctionCurrency::MXN => "MXN",
+        }
+    }
+
+    pub(crate) fn from_str(currency: &str) -> Result<Self, AppError> {
+        match currency.to_uppercase().as_str() {
+            "USD" => Ok(AuctionCurrency::USD),
+            "EUR" => Ok(AuctionCurrency::EUR),
+            "CLP" => Ok(AuctionCurrency::CLP),
+            "ARS" => Ok(AuctionCurrency::ARS),
+            "BRL" => Ok(AuctionCurrency::BRL),
+            "MXN" => Ok(AuctionCurrency::MXN),
+            _ => Err(AppError::InvalidCurrency { value: currency.to_string() }),
+        }
+    }
+
+    pub(crate) fn all_valid_currencies() -> Vec<&'static str> {
+        vec!["USD", "EUR", "CLP", "ARS", "BRL", "MXN"]
+    }
+}
+
+// Estados de una puja. Solo la puja líder queda active; al cerrar la subasta
+// pasa a won y el resto a lost. La columna sigue siendo texto.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum BidStatus {
+    #[sea_orm(string_value = "active")]
+    Active,
+    #[sea_orm(string_value = "outbid")]
+    Outbid,
+    #[sea_orm(string_value = "won")]
+    Won,
+    #[sea_orm(string_value = "lost")]
+    Lost,
+    #[sea_orm(string_value = "retracted")]
+    Retracted,
+    #[sea_orm(string_value = "cancelled")]
+    Cancelled,
+}
+
+impl BidStatus {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            BidStatus::Active => "active",
+            BidStatus::Outbid => "outbid",
+            BidStatus::Won => "won",
+            BidStatus::Lost => "lost",
+            BidStatus::Retracted => "retracted",
+            BidStatus::Cancelled => "cancelled",
+        }
+    }
+
+    // Pujas que aún compiten por la subasta
+    pub(crate) fn is_standing(&self) -> bool {
+        matches!(self, BidStatus::Active | BidStatus::Outbid)
+    }
+}
+
+// Estados de una puja automática (máximo oculto). Solo las activas vuelven a
+// pujar por su postor. La columna es texto con un CHECK de estos valores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum ProxyBidStatus {
+    #[sea_orm(string_value = "active")]
+    Active,
+    #[sea_orm(string_value = "exhausted")]
+    Exhausted,
+    #[sea_orm(string_value = "cancelled")]
+    Cancelled,
+    #[sea_orm(string_value = "retracted")]
+    Retracted,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{ActiveEnum, Iterable};
+
+    #[test]
+    fn test_auction_status_validation() {
+        // Probar que el enum funciona correctamente
+        assert_eq!(AuctionStatus::Pending.as_str(), "pending");
+        assert_eq!(AuctionStatus::Active.as_str(), "active");
+        assert_eq!(AuctionStatus::Completed.as_str(), "completed");
+        assert_eq!(AuctionStatus::Cancelled.as_str(), "cancelled");
+
+        // Probar conversión desde string
+        assert!(AuctionStatus::from_str("pending").is_ok());
+        assert!(AuctionStatus::from_str("active").is_ok());
+        assert!(AuctionStatus::from_str("completed").is_ok());
+        assert!(AuctionStatus::from_str("cancelled").is_ok());
+        assert!(AuctionStatus::from_str("invalid").is_err());
+    }
+
+    #[test]
+    fn test_status_transition_table() {
+        use AuctionStatus::*;
+        let all = [Pending, Active, Completed, Cancelled];
+        let allowed = [(Pending, Active), (Pending, Cancelled), (Active, Completed), (Active, Cancelled)];
+        for from in &all {
+            for to in &all {
+                let expected = allowed.contains(&(*from, *to));
+                assert_eq!(from.can_transition_to(to), expected, "{:?} -> {:?}", from, to);
+                // Mantener el estado actual no se considera una transición inválida
+                let check = from.check_transition(to);
+                if expected || from == to {
+                    assert!(check.is_ok(), "{:?} -> {:?}", from, to);
+                } else {
+                    assert_eq!(check.unwrap_err().code(), tonic::Code::FailedPrecondition, "{:?} -> {:?}", from, to);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_currency_validation() {
+        // Probar que el enum funciona correctamente
+        assert_eq!(AuctionCurrency::USD.as_str(), "USD");
+        assert_eq!(AuctionCurrency::EUR.as_str(), "EUR");
+        assert_eq!(AuctionCurrency::CLP.as_str(), "CLP");
+
+        // Probar conversión desde string
+        assert!(AuctionCurrency::from_str("USD").is_ok());
+        assert!(AuctionCurrency::from_str("eur").is_ok()); // Case insensitive
+        assert!(AuctionCurrency::from_str("clp").is_ok());
+        assert!(AuctionCurrency::from_str("INVALID").is_err());
+    }
+
+    #[test]
+    fn test_db_and_wire_values_match() {
+        // El texto guardado y el de la API describen lo mismo
+        for status in AuctionStatus::iter() {
+            assert_eq!(status.to_value(), status.as_str());
+        }
+        for currency in AuctionCurrency::iter() {
+            assert_eq!(currency.to_value(), currency.as_str());
+        }
+        for status in BidStatus::iter() {
+            assert_eq!(status.to_value(), status.as_str());
+            assert_eq!(BidStatus::try_from_value(&status.as_str().to_string()).unwrap(), status);
+        }
+        // Los mismos valores que permite el CHECK de proxy_bid.status
+        let proxy_statuses: Vec<String> = ProxyBidStatus::iter().map(|s| s.to_value()).collect();
+        assert_eq!(proxy_statuses, ["active", "exhausted", "cancelled", "retracted"]);
+        assert_eq!(AuctionStatus::all_valid_statuses().len(), AuctionStatus::iter().count());
+        assert_eq!(AuctionCurrency::all_valid_currencies().len(), AuctionCurrency::iter().count());
+    }
+}
@@ -0,0 +1,376 @@
+use prost::Message;
+use rust_decimal::Decimal;
+use sea_orm::DbErr;
+use tonic::{Code, Status};
+
+use crate::i18n::Locale;
+
+pub mod rpc {
+    tonic::include_proto!("google.rpc");
+}
+
+// Dominio de ErrorInfo: identifica a este servicio como origen del error
+pub const ERROR_DOMAIN: &str = "auction-ms";
+
+// Errores del servicio. Cada variante tiene un código de motivo estable
+// (ErrorInfo.reason) que los clientes pueden usar sin depender del texto.
+#[derive(Debug)]
+pub enum AppError {
+    // Validación de la solicitud (INVALID_ARGUMENT)
+    Required { field: &'static str },
+    InvalidId { field: &'static str },
+    InvalidNumber { field: &'static str },
+    NegativeValue { field: &'static str },
+    NotPositive { field: &'static str },
+    TooManyDecimals { field: &'static str, max_scale: u32 },
+    TooLong { field: &'static str, max: usize },
+    InvalidTimestamp { field: &'static str },
+    InvalidStatus { value: String },
+    InvalidCurrency { value: String },
+    InvalidDateRange,
+    StartTimeInPast,
+    EndTimeInPast,
+    ReserveBelowBasePrice,
+    InvalidSoftClose,
+    AmountConflict,
+    InvalidPageToken,
+    InvalidSort,
+    PriceRangeInverted,
+    InvalidIdempotencyKey,
+    IdempotencyKeyReused,
+    ImmutableField { field: &'static str },
+    UnknownUpdateField { path: String },
+
+    // Recursos inexistentes (NOT_FOUND)
+    AuctionNotFound,
+    BidNotFound,
+    NoBids,
+
+    // Estado incompatible con la operación (FAILED_PRECONDITION)
+    InvalidStatusTransition { from: &'static str, to: &'static str, allowed: Vec<&'static str> },
+    AuctionNotActive { status: String },
+    AuctionEnded,
+    AuctionNotStarted,
+    BidTooLow { min_required: Decimal },
+    ProxyMaxNotExceeded,
+    CancelRequiresForce { bid_count: u64 },
+    DeleteWithBids { bid_count: u64 },
+    DeleteActiveAuction,
+    AuctionNotDeleted,
+    AuctionAlreadyCancelled,
+    AuctionAlreadyClosed,
+    AuctionNotClosed,
+    BidNotRetractable { status: String },
+    RetractionWindowExpired,
+    FieldNotEditable { field: &'static str, status: String },
+
+    // La subasta cambió desde que se leyó (ABORTED)
+    VersionConflict { expected: i64, current: i64 },
+
+    // Demasiadas solicitudes (RESOURCE_EXHAUSTED)
+    RateLimited { scope: &'static str, retry_after: std::time::Duration },
+
+    // Autenticación (UNAUTHENTICATED)
+    MissingCredentials,
+    InvalidToken,
+
+    // Permisos (PERMISSION_DENIED)
+    NotBidOwner,
+    CallerMismatch { field: &'static str },
+    NotAuctionOwner,
+    SelfBidding,
+    AdminRequired { field: &'static str },
+
+    // Errores internos: el detalle solo se registra en el log del servidor
+    Database(DbErr),
+}
+
+impl From<DbErr> for AppError {
+    fn from(e: DbErr) -> Self {
+        AppError::Database(e)
+    }
+}
+
+impl AppError {
+    pub fn code(&self) -> Code {
+        use AppError::*;
+        match self {
+            Required { .. } | InvalidId { .. } | InvalidNumber { .. } | NegativeValue { .. } | NotPositive { .. }
+            | TooManyDecimals { .. } | TooLong { .. } | InvalidTimestamp { .. } | InvalidStatus { .. }
+            | InvalidCurrency { .. } | InvalidDateRange | StartTimeInPast | EndTimeInPast | ReserveBelowBasePrice | InvalidSoftClose | AmountConflict | InvalidPageToken
+            | InvalidSort | PriceRangeInverted | InvalidIdempotencyKey | IdempotencyKeyReused | ImmutableField { .. }
+            | UnknownUpdateField { .. } => Code::InvalidArgument,
+            AuctionNotFound | BidNotFound | NoBids => Code::NotFound,
+            InvalidStatusTransition { .. } | AuctionNotActive { .. } | AuctionEnded | AuctionNotStarted
+            | BidTooLow { .. } | ProxyMaxNotExceeded | CancelRequiresForce { .. } | DeleteWithBids { .. }
+            | DeleteActiveAuction | AuctionNotDeleted | AuctionAlreadyCancelled | AuctionAlreadyClosed
+            | AuctionNotClosed | BidNotRetractable { .. } | RetractionWindowExpired | FieldNotEditable { .. } => {
+                Code::FailedPrecondition
+            },
+            VersionConflict { .. } => Code::Aborted,
+            RateLimited { .. } => Code::ResourceExhausted,
+            MissingCredentials | InvalidToken => Code::Unauthenticated,
+            NotBidOwner | CallerMismatch { .. } | NotAuctionOwner | SelfBidding | AdminRequired { .. } => {
+                Code::PermissionDenied
+            },
+            Database(_) => Code::Internal,
+        }
+    }
+
+    // Código de motivo estable para ErrorInfo.reason
+    pub fn reason(&self) -> &'static str {
+        use AppError::*;
+        match self {
+            Required { .. } => "FIELD_REQUIRED",
+            InvalidId { .. } => "INVALID_ID",
+            InvalidNumber { .. } => "INVALID_NUMBER",
+            NegativeValue { .. } => "NEGATIVE_VALUE",
+            NotPositive { .. } => "NOT_POSITIVE",
+            TooManyDecimals { .. } => "INVALID_SCALE",
+            TooLong { .. } => "TOO_LONG",
+            InvalidTimestamp { .. } => "INVALID_TIMESTAMP",
+            InvalidStatus { .. } => "INVALID_STATUS",
+            InvalidCurrency { .. } => "INVALID_CURRENCY",
+            InvalidDateRange => "INVALID_DATE_RANGE",
+            StartTimeInPast => "START_TIME_IN_PAST",
+            EndTimeInPast => "END_TIME_IN_PAST",
+            ReserveBelowBasePrice => "RESERVE_BELOW_BASE_PRICE",
+            InvalidSoftClose => "INVALID_SOFT_CLOSE",
+            AmountConflict => "AMOUNT_CONFLICT",
+            InvalidPageToken => "INVALID_PAGE_TOKEN",
+            InvalidSort => "INVALID_SORT",
+            PriceRangeInverted => "PRICE_RANGE_INVERTED",
+            InvalidIdempotencyKey => "INVALID_IDEMPOTENCY_KEY",
+            IdempotencyKeyReused => "IDEMPOTENCY_KEY_REUSED",
+            ImmutableField { .. } => "IMMUTABLE_FIELD",
+            UnknownUpdateField { .. } => "UNKNOWN_FIELD",
+            AuctionNotFound => "AUCTION_NOT_FOUND",
+            BidNotFound => "BID_NOT_FOUND",
+            NoBids => "NO_BIDS",
+            InvalidStatusTransition { .. } => "INVALID_STATUS_TRANSITION",
+            AuctionNotActive { .. } => "AUCTION_NOT_ACTIVE",
+            AuctionEnded => "AUCTION_ENDED",
+            AuctionNotStarted => "AUCTION_NOT_STARTED",
+            BidTooLow { .. } => "BID_TOO_LOW",
+            ProxyMaxNotExceeded => "PROXY_MAX_NOT_EXCEEDED",
+            CancelRequiresForce { .. } | DeleteWithBids { .. } => "AUCTION_HAS_BIDS",
+            DeleteActiveAuction => "AUCTION_ACTIVE",
+            AuctionNotDeleted => "AUCTION_NOT_DELETED",
+            AuctionAlreadyCancelled => "AUCTION_ALREADY_CANCELLED",
+            AuctionAlreadyClosed => "AUCTION_ALREADY_CLOSED",
+            AuctionNotClosed => "AUCTION_NOT_CLOSED",
+            BidNotRetractable { .. } => "BID_NOT_RETRACTABLE",
+            RetractionWindowExpired => "RETRACTION_WINDOW_EXPIRED",
+            FieldNotEditable { .. } => "FIELD_NOT_EDITABLE",
+            VersionConflict { .. } => "VERSION_CONFLICT",
+            RateLimited { .. } => "RATE_LIMITED",
+            MissingCredentials => "MISSING_CREDENTIALS",
+            InvalidToken => "INVALID_TOKEN",
+            NotBidOwner => "NOT_BID_OWNER",
+            CallerMismatch { .. } => "CALLER_MISMATCH",
+            NotAuctionOwner => "NOT_AUCTION_OWNER",
+            SelfBidding => "SELF_BIDDING",
+            AdminRequired { .. } => "ADMIN_REQUIRED",
+            Database(_) => "INTERNAL",
+        }
+    }
+
+    // Campo de la solicitud que provocó el error, para BadRequest.FieldViolation
+    pub fn field(&self) -> Option<&'static str> {
+        use AppError::*;
+        match self {
+            Required { field } | InvalidId { field } | InvalidNumber { field } | NegativeValue { field }
+            | NotPositive { field } | TooManyDecimals { field, .. } | TooLong { field, .. } | InvalidTimestamp { field }
+            | CallerMismatch { field } | ImmutableField { field } | AdminRequired { field } => Some(field),
+            InvalidStatus { .. } => Some("status"),
+            InvalidCurrency { .. } => Some("currency"),
+            InvalidDateRange => Some("end_time"),
+            StartTimeInPast => Some("start_time"),
+            EndTimeInPast => Some("end_time"),
+            ReserveBelowBasePrice => Some("reserve_price"),
+            InvalidSoftClose => Some("extension_window_seconds"),
+            AmountConflict => Some("max_amount"),
+            InvalidPageToken => Some("page_token"),
+            InvalidSort => Some("sort"),
+            PriceRangeInverted => Some("min_price"),
+            InvalidIdempotencyKey | IdempotencyKeyReused => Some("idempotency_key"),
+            UnknownUpdateField { .. } => Some("update_mask"),
+            _ => None,
+        }
+    }
+
+    // Datos adicionales legibles por máquina para ErrorInfo.metadata
+    pub fn metadata(&self) -> Vec<(&'static str, String)> {
+        use AppError::*;
+        match self {
+            InvalidStatus { value } | InvalidCurrency { value } => vec![("value", value.clone())],
+            UnknownUpdateField { path } => vec![("path", path.clone())],
+            TooManyDecimals { max_scale, .. } => vec![("max_scale", max_scale.to_string())],
+            TooLong { max, .. } => vec![("max_length", max.to_string())],
+            FieldNotEditable { field, status } => vec![("field", field.to_string()), ("status", status.clone())],
+            InvalidStatusTransition { from, to, allowed } => vec![
+                ("from", from.to_string()),
+                ("to", to.to_string()),
+                ("allowed", allowed.join(",")),
+            ],
+            AuctionNotActive { status } | BidNotRetractable { status } => vec![("status", status.clone())],
+            BidTooLow { min_required } => vec![("min_required", min_required.to_string())],
+            VersionConflict { expected, current } => vec![
+                ("expected_version", expected.to_string()),
+                ("current_version", current.to_string()),
+            ],
+            RateLimited { scope, .. } => vec![
+                ("scope", scope.to_string()),
+                ("retry_after_seconds", self.retry_after_secs().unwrap_or_default().to_string()),
+            ],
+            CancelRequiresForce { bid_count } | DeleteWithBids { bid_count } => {
+                vec![("bid_count", bid_count.to_string())]
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    // Segundos enteros a esperar antes de reintentar, redondeados hacia arriba
+    pub fn retry_after_secs(&self) -> Option<u64> {
+        match self {
+            AppError::RateLimited { retry_after, .. } => Some(retry_after.as_secs_f64().ceil().max(1.0) as u64),
+            _ => None,
+        }
+    }
+
+    // Mensaje en el idioma de la solicitud en curso
+    pub fn message(&self) -> String {
+        self.message_in(crate::i18n::current())
+    }
+
+    pub fn message_in(&self, locale: Locale) -> String {
+        locale.message(self)
+    }
+
+    // Detalles google.rpc serializados: ErrorInfo y LocalizedMessage siempre,
+    // RetryInfo al limitar la tasa y BadRequest si hay campo
+    fn details(&self, message: &str) -> Vec<u8> {
+        let info = rpc::ErrorInfo {
+            reason: self.reason().to_string(),
+            domain: ERROR_DOMAIN.to_string(),
+            metadata: self.metadata().into_iter().map(|(k, v)| (k.to_string(), v)).collect(),
+        };
+        let mut details = vec![prost_types::Any {
+            type_url: "type.googleapis.com/google.rpc.ErrorInfo".to_string(),
+            value: info.encode_to_vec(),
+        }];
+        let localized = rpc::LocalizedMessage {
+            locale: crate::i18n::current().code().to_string(),
+            message: message.to_string(),
+        };
+        details.push(prost_types::Any {
+            type_url: "type.googleapis.com/google.rpc.LocalizedMessage".to_string(),
+            value: localized.encode_to_vec(),
+        });
+        if let AppError::RateLimited { retry_after, .. } = self {
+            let retry_info = rpc::RetryInfo {
+                retry_delay: Some(prost_types::Duration {
+                    seconds: retry_after.as_secs() as i64,
+                    nanos: retry_after.subsec_nanos() as i32,
+                }),
+            };
+            details.push(prost_types::Any {
+                type_url: "type.googleapis.com/google.rpc.RetryInfo".to_string(),
+                value: retry_info.encode_to_vec(),
+            });
+        }
+        if let Some(field) = self.field() {
+            let bad_request = rpc::BadRequest {
+                field_violations: vec![rpc::bad_request::FieldViolation {
+                    field: field.to_string(),
+                    description: message.to_string(),
+                }],
+            };
+            details.push(prost_types::Any {
+                type_url: "type.googleapis.com/google.rpc.BadRequest".to_string(),
+                value: bad_request.encode_to_vec(),
+            });
+        }
+        rpc::Status { code: self.code() as i32, message: message.to_string(), details }.encode_to_vec()
+    }
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.reason(), self.message())
+    }
+}
+
+impl From<AppError> for Status {
+    fn from(err: AppError) -> Self {
+        if let AppError::Database(e) = &err {
+            log::error!("Error de base de datos: {}", e);
+        }
+        let message = err.message();
+        let mut status = Status::with_details(err.code(), message.clone(), err.details(&message).into());
+        // Indicación estándar para clientes que no leen los detalles
+        if let Some(secs) = err.retry_after_secs() {
+            status.metadata_mut().insert("retry-after", secs.into());
+        }
+        status
+    }
+}
+
+// Lee ErrorInfo de un Status generado por este servicio
+pub fn error_info(status: &Status) -> Option<rpc::ErrorInfo> {
+    let details = rpc::Status::decode(status.details()).ok()?;
+    details
+        .details
+        .iter()
+        .find(|any| any.type_url.ends_with("google.rpc.ErrorInfo"))
+        .and_then(|any| rpc::ErrorInfo::decode(any.value.as_slice()).ok())
+}
+
+// Lee BadRequest de un Status generado por este servicio
+pub fn bad_request(status: &Status) -> Option<rpc::BadRequest> {
+    let details = rpc::Status::decode(status.details()).ok()?;
+    details
+        .details
+        .iter()
+        .find(|any| any.type_url.ends_with("google.rpc.BadRequest"))
+        .and_then(|any| rpc::BadRequest::decode(any.value.as_slice()).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bid_too_low_details() {
+        let status = Status::from(AppError::BidTooLow { min_required: Decimal::from(130) });
+        assert_eq!(status.code(), Code::FailedPrecondition);
+        assert_eq!(status.message(), "La puja debe ser al menos 130");
+
+        let info = error_info(&status).unwrap();
+        assert_eq!(info.reason, "BID_TOO_LOW");
+        assert_eq!(info.domain, ERROR_DOMAIN);
+        assert_eq!(info.metadata.get("min_required").map(String::as_str), Some("130"));
+        assert!(bad_request(&status).is_none());
+    }
+
+    #[test]
+    fn test_field_violation_details() {
+        let status = Status::from(AppError::InvalidNumber { field: "base_price" });
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(error_info(&status).unwrap().reason, "INVALID_NUMBER");
+
+        let violations = bad_request(&status).unwrap().field_violations;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "base_price");
+        assert_eq!(violations[0].description, "base_price debe ser un número válido");
+    }
+
+    #[test]
+    fn test_database_errors_are_sanitised() {
+        let err = AppError::from(DbErr::Custom("relation \"auction\" does not exist".to_string()));
+        let status = Status::from(err);
+        assert_eq!(status.code(), Code::Internal);
+        assert!(!status.message().contains("auction"));
+        assert_eq!(error_info(&status).unwrap().reason, "INTERNAL");
+    }
+}
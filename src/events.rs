@@ -0,0 +1,185 @@
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::Status;
+
+use crate::grpc_server::auction::{AuctionEvent, AuctionEventType};
+use crate::domain::AuctionStatus;
+use crate::proto_enums::{auction_status_from_proto, auction_status_to_proto};
+
+// Eventos retenidos para suscriptores atrasados antes de descartarlos
+pub const DEFAULT_CHANNEL_CAPACITY: usize = 1024;
+// Mensajes en cola por cada stream gRPC antes de frenar al reenviador
+const SUBSCRIBER_BUFFER: usize = 64;
+
+// Lee la capacidad del canal de eventos desde EVENT_CHANNEL_CAPACITY
+pub fn capacity_from_env() -> usize {
+    std::env::var("EVENT_CHANNEL_CAPACITY")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|capacity| *capacity > 0)
+        .unwrap_or(DEFAULT_CHANNEL_CAPACITY)
+}
+
+// Crea un evento vacío del tipo indicado con la hora actual
+pub fn new_event(auction_id: uuid::Uuid, event_type: AuctionEventType) -> AuctionEvent {
+    AuctionEvent {
+        auction_id: auction_id.to_string(),
+        r#type: event_type as i32,
+        occurred_at: Some(prost_types::Timestamp::from(std::time::SystemTime::now())),
+        ..Default::default()
+    }
+}
+
+// Evento de cambio de estado, con el estado como texto y como valor tipado
+pub fn status_changed(auction_id: uuid::Uuid, status: AuctionStatus) -> AuctionEvent {
+    let mut event = new_event(auction_id, AuctionEventType::StatusChanged);
+    event.status = status.as_str().to_string();
+    event.status_value = auction_status_to_proto(status) as i32;
+    event
+}
+
+// Indica si el evento cierra definitivamente la subasta
+fn is_final(event: &AuctionEvent) -> bool {
+    event.r#type == AuctionEventType::StatusChanged as i32
+        && matches!(auction_status_from_proto(event.status_value), Ok(Some(status)) if status.is_final())
+}
+
+// Canal en memoria que reparte los eventos de todas las subastas a los streams
+// de WatchAuction. Publicar nunca bloquea: un suscriptor lento pierde los eventos
+// más antiguos y recibe un EVENTS_DROPPED en su lugar.
+#[derive(Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<AuctionEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        EventBus::new(DEFAULT_CHANNEL_CAPACITY)
+    }
+}
+
+impl EventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        EventBus { sender }
+    }
+
+    // Publica un evento; sin suscriptores simplemente se descarta
+    pub fn publish(&self, event: AuctionEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    pub fn receiver(&self) -> broadcast::Receiver<AuctionEvent> {
+        self.sender.subscribe()
+    }
+
+    // Stream de eventos de una subasta. `initial` se envía primero; el receptor
+    // debe obtenerse antes de leer ese estado para no perder eventos intermedios.
+    // El stream termina cuando la subasta se completa o cancela, o cuando el
+    // cliente se desconecta.
+    pub fn watch(
+        auction_id: uuid::Uuid,
+        mut receiver: broadcast::Receiver<AuctionEvent>,
+        initial: AuctionEvent,
+    ) -> ReceiverStream<Result<AuctionEvent, Status>> {
+        let (tx, rx) = mpsc::channel(SUBSCRIBER_BUFFER);
+        tokio::spawn(async move {
+            let watched = auction_id.to_string();
+            let finished = initial.auction.as_ref().is_some_and(|a| {
+                matches!(auction_status_from_proto(a.status_value), Ok(Some(status)) if status.is_final())
+            });
+            if tx.send(Ok(initial)).await.is_err() || finished {
+                return;
+            }
+            loop {
+                let event = match receiver.recv().await {
+                    Ok(event) if event.auction_id == watched => event,
+                    Ok(_) => continue,
+                    Err(broadcast::error::RecvError::Lagged(missed)) => {
+                        log::warn!("Suscriptor de la subasta {} atrasado: {} eventos descartados", auction_id, missed);
+                        let mut dropped = new_event(auction_id, AuctionEventType::EventsDropped);
+                        dropped.dropped_events = missed;
+                        dropped
+                    },
+                    Err(broadcast::error::RecvError::Closed) => return,
+                };
+                let last = is_final(&event);
+                if tx.send(Ok(event)).await.is_err() || last {
+                    return;
+                }
+            }
+        });
+        ReceiverStream::new(rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_stream::StreamExt;
+
+    fn snapshot(auction_id: uuid::Uuid, status: AuctionStatus) -> AuctionEvent {
+        let mut event = new_event(auction_id, AuctionEventType::Snapshot);
+        event.auction = Some(crate::grpc_server::auction::Auction {
+            id: auction_id.to_string(),
+            status: status.as_str().to_string(),
+            status_value: auction_status_to_proto(status) as i32,
+            ..Default::default()
+        });
+        event
+    }
+
+    async fn next_type(stream: &mut ReceiverStream<Result<AuctionEvent, Status>>) -> Option<AuctionEventType> {
+        let next = tokio::time::timeout(std::time::Duration::from_secs(1), stream.next()).await.unwrap();
+        next.map(|e| AuctionEventType::try_from(e.unwrap().r#type).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_watch_filters_by_auction_and_ends_on_completion() {
+        let bus = EventBus::new(16);
+        let watched = uuid::Uuid::new_v4();
+        let mut stream = EventBus::watch(watched, bus.receiver(), snapshot(watched, AuctionStatus::Active));
+
+        bus.publish(new_event(uuid::Uuid::new_v4(), AuctionEventType::BidPlaced));
+        bus.publish(new_event(watched, AuctionEventType::BidPlaced));
+        bus.publish(status_changed(watched, AuctionStatus::Completed));
+        bus.publish(new_event(watched, AuctionEventType::BidPlaced));
+
+        assert_eq!(next_type(&mut stream).await, Some(AuctionEventType::Snapshot));
+        assert_eq!(next_type(&mut stream).await, Some(AuctionEventType::BidPlaced));
+        assert_eq!(next_type(&mut stream).await, Some(AuctionEventType::StatusChanged));
+        assert_eq!(next_type(&mut stream).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_watch_finished_auction_only_sends_snapshot() {
+        let bus = EventBus::new(16);
+        let auction_id = uuid::Uuid::new_v4();
+        let mut stream = EventBus::watch(auction_id, bus.receiver(), snapshot(auction_id, AuctionStatus::Cancelled));
+
+        assert_eq!(next_type(&mut stream).await, Some(AuctionEventType::Snapshot));
+        assert_eq!(next_type(&mut stream).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_is_told_about_dropped_events() {
+        let bus = EventBus::new(4);
+        let auction_id = uuid::Uuid::new_v4();
+        let receiver = bus.receiver();
+
+        // Se publica más de lo que el canal retiene antes de que el stream lea
+        for _ in 0..10 {
+            bus.publish(new_event(auction_id, AuctionEventType::BidPlaced));
+        }
+        let mut stream = EventBus::watch(auction_id, receiver, snapshot(auction_id, AuctionStatus::Active));
+
+        assert_eq!(next_type(&mut stream).await, Some(AuctionEventType::Snapshot));
+        let dropped = stream.next().await.unwrap().unwrap();
+        assert_eq!(dropped.r#type, AuctionEventType::EventsDropped as i32);
+        assert_eq!(dropped.auction_id, auction_id.to_string());
+        assert_eq!(dropped.dropped_events, 6);
+        for _ in 0..4 {
+            assert_eq!(next_type(&mut stream).await, Some(AuctionEventType::BidPlaced));
+        }
+    }
+}
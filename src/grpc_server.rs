@@ -1,8 +1,26 @@
-use tonic::{transport::Server, Request, Response, Status};
-use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set}; 
+use tonic::{service::interceptor::InterceptedService, transport::Server, Request, Response, Status};
+use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait};
 use uuid::Uuid;
 use crate::models::auction::{Entity as AuctionEntity, ActiveModel as AuctionActiveModel, Model as AuctionModel};
 use crate::models::bid::{Entity as BidEntity, ActiveModel as BidActiveModel, Model as BidModel};
+use crate::models::auction_closure::{ActiveModel as AuctionClosureActiveModel, Model as AuctionClosureModel};
+use crate::models::auction_result::{Entity as AuctionResultEntity, Model as AuctionResultModel};
+use crate::models::proxy_bid::{Entity as ProxyBidEntity, ActiveModel as ProxyBidActiveModel};
+use crate::auth::{AuthInterceptor, JwtVerifier};
+use crate::domain::{AuctionCurrency, AuctionStatus, BidStatus, ProxyBidStatus};
+use crate::error::AppError;
+use crate::events::{self, EventBus};
+use crate::i18n::{Locale, LocaleService};
+use crate::idempotency::{self, IdempotentRequest, Operation};
+use crate::policy::{self, Action};
+use crate::proto_enums::{
+    auction_status_from_request, auction_status_to_proto, bid_status_to_proto, currency_from_request, currency_to_proto,
+};
+use crate::proxy_bidding::{self, Ceiling, CeilingKind};
+use crate::rate_limit::{BidRateLimiter, RateLimitService};
+use crate::retraction::RetractionPolicy;
+use crate::update_mask::UpdateField;
+use sea_orm::sea_query::Expr;
 use prost_types::Timestamp;
 
 
@@ -16,83 +34,29 @@ use auction::*;
 #[derive(Clone)]
 pub struct MyAuctionService {
     db: DatabaseConnection,
+    events: EventBus,
+    retraction: RetractionPolicy,
+    idempotency_ttl: std::time::Duration,
 }
 
-// Enum para los estados válidos de subasta
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum AuctionStatus {
-    Pending,
-    Active, 
-    Completed,
-    Cancelled,
-}
-
-impl AuctionStatus {
-    fn as_str(&self) -> &'static str {
-        match self {
-            AuctionStatus::Pending => "pending",
-            AuctionStatus::Active => "active",
-            AuctionStatus::Completed => "completed",
-            AuctionStatus::Cancelled => "cancelled",
-        }
-    }
-
-    fn from_str(status: &str) -> Result<Self, Status> {
-        match status.to_lowercase().as_str() {
-            "pending" => Ok(AuctionStatus::Pending),
-            "active" => Ok(AuctionStatus::Active),
-            "completed" => Ok(AuctionStatus::Completed),
-            "cancelled" => Ok(AuctionStatus::Cancelled),
-            _ => Err(Status::invalid_argument(
-                "Status inválido. Valores permitidos: pending, active, completed, cancelled"
-            )),
-        }
-    }
-
-    fn all_valid_statuses() -> Vec<&'static str> {
-        vec!["pending", "active", "completed", "cancelled"]
-    }
-}
-
-// Enum para las monedas válidas
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum AuctionCurrency {
-    USD,
-    EUR, 
-    CLP,
-    ARS,
-    BRL,
-    MXN,
-}
-
-impl AuctionCurrency {
-    fn as_str(&self) -> &'static str {
-        match self {
-            AuctionCurrency::USD => "USD",
-            AuctionCurrency::EUR => "EUR",
-            AuctionCurrency::CLP => "CLP",
-            AuctionCurrency::ARS => "ARS",
-            AuctionCurrency::BRL => "BRL",
-            AuctionCurrency::MXN => "MXN",
+impl MyAuctionService {
+    pub fn new(db: DatabaseConnection, events: EventBus) -> Self {
+        MyAuctionService {
+            db,
+            events,
+            retraction: RetractionPolicy::default(),
+            idempotency_ttl: std::time::Duration::from_secs(idempotency::DEFAULT_TTL_SECS),
         }
     }
 
-    fn from_str(currency: &str) -> Result<Self, Status> {
-        match currency.to_uppercase().as_str() {
-            "USD" => Ok(AuctionCurrency::USD),
-            "EUR" => Ok(AuctionCurrency::EUR),
-            "CLP" => Ok(AuctionCurrency::CLP),
-            "ARS" => Ok(AuctionCurrency::ARS),
-            "BRL" => Ok(AuctionCurrency::BRL),
-            "MXN" => Ok(AuctionCurrency::MXN),
-            _ => Err(Status::invalid_argument(
-                "Moneda inválida. Valores permitidos: USD, EUR, CLP, ARS, BRL, MXN"
-            )),
-        }
+    pub fn with_retraction_policy(mut self, retraction: RetractionPolicy) -> Self {
+        self.retraction = retraction;
+        self
     }
 
-    fn all_valid_currencies() -> Vec<&'static str> {
-        vec!["USD", "EUR", "CLP", "ARS", "BRL", "MXN"]
+    pub fn with_idempotency_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.idempotency_ttl = ttl;
+        self
     }
 }
 
@@ -103,7 +67,20 @@ impl AuctionService for MyAuctionService {
         request: Request<CreateAuctionRequest>,
     ) -> Result<Response<CreateAuctionResponse>, Status> {
         log::info!("=== INICIANDO CREACIÓN DE SUBASTA ===");
-        let req = request.into_inner();
+        // El dueño de la subasta es siempre el usuario autenticado
+        let identity = crate::auth::caller(&request)?;
+        let header_key = idempotency::header_key(&request);
+        let mut req = request.into_inner();
+        req.user_id = crate::auth::resolve_user_id(&identity, &req.user_id, "user_id")?;
+
+        // Un reintento con la misma clave devuelve la subasta ya creada
+        let idempotent = idempotency::resolve_key(std::mem::take(&mut req.idempotency_key), header_key)?
+            .map(|key| IdempotentRequest::new(&req.user_id, Operation::CreateAuction, key, &req));
+        if let Some(idempotent) = &idempotent {
+            if let Some(previous) = idempotent.replay(&self.db, chrono::Utc::now()).await? {
+                return Ok(Response::new(previous));
+            }
+        }
         
         // Debug: Log the raw request structure
         log::debug!("Raw request: {:?}", req);
@@ -116,61 +93,35 @@ impl AuctionService for MyAuctionService {
         
         // Validar fechas antes de crear la subasta
         log::debug!("Validando fechas de inicio y fin");
-        let start_time = match proto_timestamp_to_naive(&req.start_time) {
+        let start_time = match proto_timestamp_to_utc(&req.start_time, "start_time") {
             Ok(time) => {
                 log::debug!("Fecha de inicio convertida: {}", time);
                 time
             },
             Err(e) => {
                 log::error!("Error al convertir fecha de inicio: {:?}", e);
-                return Err(e);
+                return Err(e.into());
             }
         };
         
-        let end_time = match proto_timestamp_to_naive(&req.end_time) {
+        let end_time = match proto_timestamp_to_utc(&req.end_time, "end_time") {
             Ok(time) => {
                 log::debug!("Fecha de fin convertida: {}", time);
                 time
             },
             Err(e) => {
                 log::error!("Error al convertir fecha de fin: {:?}", e);
-                return Err(e);
+                return Err(e.into());
             }
         };
         
-        if let Err(e) = validate_date_range(&start_time, &end_time) {
-            log::error!("Error en validación de rango de fechas: {:?}", e);
-            return Err(e);
-        }
-        log::info!("Validación de fechas exitosa");
-        
         // Validar IDs como strings
         log::debug!("Validando IDs");
-        if req.user_id.is_empty() {
-            log::error!("user_id vacío");
-            return Err(Status::invalid_argument("user_id no puede estar vacío"));
-        }
-        
         if req.item_id.is_empty() {
             log::error!("item_id vacío");
-            return Err(Status::invalid_argument("item_id no puede estar vacío"));
-        }
-
-        // Validar que category no esté vacía
-        if req.category.is_empty() {
-            log::error!("category vacía - valor recibido: '{}'", req.category);
-            log::error!("category bytes: {:?}", req.category.as_bytes());
-            return Err(Status::invalid_argument("category no puede estar vacía"));
-        }
-        
-        // También validar que no contenga solo espacios en blanco
-        if req.category.trim().is_empty() {
-            log::error!("category contiene solo espacios en blanco: '{}'", req.category);
-            return Err(Status::invalid_argument("category no puede contener solo espacios en blanco"));
+            return Err(AppError::Required { field: "item_id" }.into());
         }
         
-        log::debug!("Category válida después de validación: '{}'", req.category);
-        
         log::debug!("User ID válido (string): {}", req.user_id);
         log::debug!("Item ID válido (string): {}", req.item_id);
         log::debug!("Category válida: {}", req.category);
@@ -180,12 +131,20 @@ impl AuctionService for MyAuctionService {
         let base_price = validate_numeric_string(&req.base_price, "base_price")?;
         let min_bid_increment = validate_numeric_string(&req.min_bid_increment, "min_bid_increment")?;
         
-        // Validar moneda
-        let currency = if req.currency.is_empty() {
-            log::info!("Currency no especificada, usando USD por defecto");
-            AuctionCurrency::USD
+        // Precio de reserva opcional
+        let reserve_price = if req.reserve_price.is_empty() {
+            None
         } else {
-            AuctionCurrency::from_str(&req.currency)?
+            Some(validate_numeric_string(&req.reserve_price, "reserve_price")?)
+        };
+        
+        // Validar moneda: currency_value o, para clientes anteriores, el texto de currency
+        let currency = match currency_from_request(req.currency_value, &req.currency)? {
+            Some(currency) => currency,
+            None => {
+                log::info!("Currency no especificada, usando USD por defecto");
+                AuctionCurrency::USD
+            },
         };
         log::debug!("Moneda válida: {}", currency.as_str());
         
@@ -209,67 +168,93 @@ impl AuctionService for MyAuctionService {
             base_price: Set(base_price),
             min_bid_increment: Set(min_bid_increment),
             highest_bid: Set(Default::default()),
-            status: Set(auction_status.as_str().to_string()),
-            currency: Set(currency.as_str().to_string()),
+            status: Set(auction_status),
+            currency: Set(currency),
+            reserve_price: Set(reserve_price),
+            extension_window_secs: Set(req.extension_window_seconds),
+            extension_secs: Set(req.extension_seconds),
+            max_extensions: Set(req.max_extensions),
+            extensions_count: Set(0),
+            deleted_at: Set(None),
+            version: Set(1),
         };
         
+        // Mismas reglas que UpdateAuction: fechas, precios positivos, decimales
+        // según la moneda, longitudes y cierre suave
+        if let Err(e) = crate::validation::validate_auction(&auction, chrono::Utc::now()) {
+            log::error!("Subasta inválida: {}", e);
+            return Err(e.into());
+        }
+        
         log::info!("Modelo creado - Intentando insertar en DB...");
         log::debug!("Valores del modelo: id={}, user_id={}, category={}, status={}, currency={}", 
             auction_id, req.user_id, req.category, auction_status.as_str(), currency.as_str());
             
-        match auction.insert(&self.db).await {
+        // La subasta y la respuesta asociada a la clave se guardan juntas
+        let txn = self.db.begin().await
+            .map_err(AppError::from)?;
+        match auction.insert(&txn).await {
             Ok(inserted) => {
                 log::info!("✅ Subasta creada exitosamente con ID: {}", inserted.id);
                 log::info!("✅ Datos verificados - categoría guardada: '{}', estado guardado: '{}'", 
-                    inserted.category, inserted.status);
+                    inserted.category, inserted.status.as_str());
                 log::debug!("Datos de subasta insertada: título='{}', usuario='{}', item='{}', categoría='{}', precio_base={}, moneda='{}'", 
-                    inserted.title, inserted.user_id, inserted.item_id, inserted.category, inserted.base_price, inserted.currency);
+                    inserted.title, inserted.user_id, inserted.item_id, inserted.category, inserted.base_price, inserted.currency.as_str());
                 let proto_auction = map_model_to_proto(&inserted);
-                log::info!("=== CREACIÓN DE SUBASTA COMPLETADA ===");
-                Ok(Response::new(CreateAuctionResponse {
+                let response = CreateAuctionResponse {
                     auction: Some(proto_auction),
-                }))
+                };
+                let now = chrono::Utc::now();
+                if let Some(previous) = idempotency::commit(txn, &self.db, idempotent.as_ref(), &response, now, self.idempotency_ttl).await? {
+                    return Ok(Response::new(previous));
+                }
+                log::info!("=== CREACIÓN DE SUBASTA COMPLETADA ===");
+                Ok(Response::new(response))
             },
             Err(e) => {
                 log::error!("❌ Error al insertar subasta en base de datos: {}", e);
                 log::error!("Tipo de error: {:?}", e);
                 log::error!("Detalles completos del error: {:#?}", e);
                 
-                // Intentar obtener más detalles del error de base de datos
-                let error_msg = format!("Error de base de datos: {}. Verifique que la tabla 'auction' exista y tenga todas las columnas requeridas.", e);
-                Err(Status::internal(error_msg))
+                // El detalle queda en el log; el cliente recibe un error interno genérico
+                Err(AppError::from(e).into())
             }
         }
     }
 
     async fn list_auctions(
         &self,
-        _request: Request<ListAuctionsRequest>,
+        request: Request<ListAuctionsRequest>,
     ) -> Result<Response<ListAuctionsResponse>, Status> {
-        log::info!("Recibida solicitud list_auctions");
-        let auctions = AuctionEntity::find()
+        if request.get_ref().include_deleted {
+            policy::authorize_include_deleted(&crate::auth::caller(&request)?)?;
+        }
+        let req = request.into_inner();
+        log::info!("Recibida solicitud list_auctions: {:?}", req);
+
+        // Filtros, orden y paginación se resuelven en la consulta
+        let (query, page_size) = crate::listing::build_query(&req)?;
+        let bids_limit = crate::listing::bids_limit(&req)?;
+        let auctions = query
             .all(&self.db)
             .await
-            .map_err(|e| Status::internal(format!("DB error: {}", e)))?;
+            .map_err(AppError::from)?;
+        let (auctions, next_page_token) = crate::listing::finish_page(&req, auctions, page_size);
 
-        let mut proto_auctions = Vec::new();
-        
-        // Para cada subasta, obtener sus pujas
-        for auction in auctions {
-            let bids = BidEntity::find()
-                .filter(crate::models::bid::Column::AuctionId.eq(auction.id))
-                .order_by_desc(crate::models::bid::Column::CreatedAt)
-                .all(&self.db)
+        // Las pujas de toda la página se cargan en una sola consulta, solo si se piden
+        let proto_auctions: Vec<Auction> = if req.include_bids {
+            let bids = crate::listing::load_bids(&self.db, &auctions, bids_limit)
                 .await
-                .map_err(|e| Status::internal(format!("DB error: {}", e)))?;
-            
-            let proto_auction = map_model_to_proto_with_bids(&auction, &bids);
-            proto_auctions.push(proto_auction);
-        }
-        
-        log::info!("Retornando {} subastas con sus pujas incluidas", proto_auctions.len());
+                .map_err(AppError::from)?;
+            auctions.iter().zip(bids).map(|(auction, bids)| map_model_to_proto_with_bids(auction, &bids)).collect()
+        } else {
+            auctions.iter().map(map_model_to_proto).collect()
+        };
+
+        log::info!("Retornando {} subastas (pujas incluidas: {})", proto_auctions.len(), req.include_bids);
         Ok(Response::new(ListAuctionsResponse {
             auctions: proto_auctions,
+            next_page_token,
         }))
     }
 
@@ -277,18 +262,22 @@ impl AuctionService for MyAuctionService {
         &self,
         request: Request<GetAuctionRequest>,
     ) -> Result<Response<GetAuctionResponse>, Status> {
+        if request.get_ref().include_deleted {
+            policy::authorize_include_deleted(&crate::auth::caller(&request)?)?;
+        }
         let req = request.into_inner();
         log::info!("Recibida solicitud get_auction con id={}", req.id);
-        let id = uuid::Uuid::parse_str(&req.id).map_err(|_| Status::invalid_argument("id inválido"))?;
+        let id = uuid::Uuid::parse_str(&req.id).map_err(|_| AppError::InvalidId { field: "id" })?;
         
-        // Obtener la subasta
-        let auction = AuctionEntity::find_by_id(id)
+        // Obtener la subasta; las eliminadas solo con include_deleted
+        let query = if req.include_deleted { AuctionEntity::find_by_id(id) } else { find_live_auction(id) };
+        let auction = query
             .one(&self.db)
             .await
-            .map_err(|e| Status::internal(format!("DB error: {}", e)))?;
+            .map_err(AppError::from)?;
         
         let Some(auction_model) = auction else {
-            return Err(Status::not_found("Subasta no encontrada"));
+            return Err(AppError::AuctionNotFound.into());
         };
 
         // Obtener todas las pujas de la subasta
@@ -297,7 +286,7 @@ impl AuctionService for MyAuctionService {
             .order_by_desc(crate::models::bid::Column::CreatedAt)
             .all(&self.db)
             .await
-            .map_err(|e| Status::internal(format!("DB error: {}", e)))?;
+            .map_err(AppError::from)?;
 
         // Convertir a proto con las pujas incluidas
         let proto_auction = map_model_to_proto_with_bids(&auction_model, &bids);
@@ -311,65 +300,131 @@ impl AuctionService for MyAuctionService {
         &self,
         request: Request<UpdateAuctionRequest>,
     ) -> Result<Response<UpdateAuctionResponse>, Status> {
+        let identity = crate::auth::caller(&request)?;
         let req = request.into_inner();
         log::info!("Recibida solicitud update_auction con id={}", req.id);
-        let id = uuid::Uuid::parse_str(&req.id).map_err(|_| Status::invalid_argument("id inválido"))?;
-        let found = AuctionEntity::find_by_id(id)
-            .one(&self.db)
+        let id = uuid::Uuid::parse_str(&req.id).map_err(|_| AppError::InvalidId { field: "id" })?;
+        let txn = self.db.begin().await
+            .map_err(AppError::from)?;
+        let found = find_live_auction(id)
+            .lock_exclusive()
+            .one(&txn)
             .await
-            .map_err(|e| Status::internal(format!("DB error: {}", e)))?;
+            .map_err(AppError::from)?;
         let Some(model) = found else {
-            return Err(Status::not_found("Subasta no encontrada"));
+            return Err(AppError::AuctionNotFound.into());
         };
-        let mut active: AuctionActiveModel = model.into();
-        
-        if !req.title.is_empty() { 
-            active.title = Set(req.title); 
-        }
-        if !req.description.is_empty() { 
-            active.description = Set(Some(req.description)); 
+        policy::authorize(&identity, Action::UpdateAuction, &model)?;
+        // El cliente indica la versión que leyó; 0 omite la comprobación
+        if req.expected_version != 0 && req.expected_version != model.version {
+            return Err(AppError::VersionConflict { expected: req.expected_version, current: model.version }.into());
         }
-        if !req.category.is_empty() { 
-            active.category = Set(req.category); 
-        }
-        if let Some(ts) = req.start_time { 
-            active.start_time = Set(proto_timestamp_to_naive(&Some(ts))?); 
-        }
-        if let Some(ts) = req.end_time { 
-            active.end_time = Set(proto_timestamp_to_naive(&Some(ts))?); 
-        }
-        if !req.base_price.is_empty() { 
-            active.base_price = Set(validate_numeric_string(&req.base_price, "base_price")?); 
+        let previous_status = model.status;
+        let fields = crate::update_mask::requested_fields(&req)?;
+
+        // Precios y fechas quedan fijos una vez que la subasta deja de estar pendiente
+        if previous_status != AuctionStatus::Pending {
+            if let Some(field) = fields.iter().find(|f| f.pending_only()) {
+                return Err(AppError::FieldNotEditable { field: field.path(), status: previous_status.as_str().to_string() }.into());
+            }
         }
-        if !req.min_bid_increment.is_empty() { 
-            active.min_bid_increment = Set(validate_numeric_string(&req.min_bid_increment, "min_bid_increment")?); 
+
+        let mut active: AuctionActiveModel = model.into();
+        let mut completing = false;
+        let mut cancelling = false;
+
+        for field in &fields {
+            match field {
+                UpdateField::Title => active.title = Set(req.title.clone()),
+                // Una descripción vacía la borra
+                UpdateField::Description => {
+                    active.description = Set(Some(req.description.clone()).filter(|d| !d.is_empty()));
+                },
+                UpdateField::Category => active.category = Set(req.category.trim().to_string()),
+                UpdateField::StartTime => {
+                    active.start_time = Set(proto_timestamp_to_utc(&req.start_time, "start_time")?);
+                },
+                UpdateField::EndTime => {
+                    active.end_time = Set(proto_timestamp_to_utc(&req.end_time, "end_time")?);
+                },
+                UpdateField::BasePrice => {
+                    active.base_price = Set(validate_numeric_string(&req.base_price, "base_price")?);
+                },
+                UpdateField::MinBidIncrement => {
+                    active.min_bid_increment = Set(validate_numeric_string(&req.min_bid_increment, "min_bid_increment")?);
+                },
+                // Un precio de reserva vacío elimina la reserva
+                UpdateField::ReservePrice => {
+                    active.reserve_price = Set(if req.reserve_price.is_empty() {
+                        None
+                    } else {
+                        Some(validate_numeric_string(&req.reserve_price, "reserve_price")?)
+                    });
+                },
+                UpdateField::Currency => {
+                    let new_currency = currency_from_request(req.currency_value, &req.currency)?
+                        .ok_or_else(|| AppError::InvalidCurrency { value: req.currency.clone() })?;
+                    log::info!("Cambiando currency de subasta a: {}", new_currency.as_str());
+                    active.currency = Set(new_currency);
+                },
+                // Todo en cero desactiva el cierre suave
+                UpdateField::SoftClose => {
+                    active.extension_window_secs = Set(req.extension_window_seconds);
+                    active.extension_secs = Set(req.extension_seconds);
+                    active.max_extensions = Set(req.max_extensions);
+                },
+                // El estado se aplica al final porque la activación depende de start_time
+                UpdateField::Status => {},
+            }
         }
-        if !req.highest_bid.is_empty() { 
-            active.highest_bid = Set(Some(validate_numeric_string(&req.highest_bid, "highest_bid")?)); 
+
+        // Las reglas se comprueban sobre la subasta resultante, no solo sobre lo enviado
+        crate::validation::validate_auction(&active, chrono::Utc::now())?;
+
+        // Validar y actualizar status respetando la tabla de transiciones
+        if fields.contains(&UpdateField::Status) {
+            let current_status = previous_status;
+            let new_status = auction_status_from_request(req.status_value, &req.status)?
+                .ok_or_else(|| AppError::InvalidStatus { value: req.status.clone() })?;
+            current_status.check_transition(&new_status)?;
+
+            if new_status != current_status {
+                log::info!("Cambiando status de subasta: {} -> {}", current_status.as_str(), new_status.as_str());
+
+                // Una activación anticipada adelanta start_time para que la subasta acepte pujas;
+                // si start_time ya pasó se conserva
+                let now = chrono::Utc::now();
+                if new_status == AuctionStatus::Active && *active.start_time.as_ref() > now {
+                    log::info!("Activación anticipada - start_time adelantado al momento actual");
+                    active.start_time = Set(now);
+                }
+                completing = new_status == AuctionStatus::Completed;
+                cancelling = new_status == AuctionStatus::Cancelled;
+                active.status = Set(new_status); 
+            }
         }
         
-        // Validar y actualizar currency si se proporciona
-        if !req.currency.is_empty() {
-            let new_currency = AuctionCurrency::from_str(&req.currency)?;
-            log::info!("Cambiando currency de subasta a: {}", new_currency.as_str());
-            active.currency = Set(new_currency.as_str().to_string());
+        let updated = update_auction_versioned(&txn, active).await?;
+
+        // Al cancelar, las pujas vigentes quedan anuladas
+        if cancelling {
+            crate::settlement::cancel_bids(&txn, updated.id)
+                .await
+                .map_err(AppError::from)?;
         }
-        
-        // Validar y actualizar status si se proporciona
-        if !req.status.is_empty() { 
-            let new_status = AuctionStatus::from_str(&req.status)?;
-            log::info!("Cambiando status de subasta a: {}", new_status.as_str());
-            
-            // Si el status cambia a "active", establecer start_time al momento actual
-            if new_status == AuctionStatus::Active {
-                log::info!("Activando subasta - estableciendo start_time al momento actual");
-                active.start_time = Set(chrono::Utc::now().naive_utc());
-            }
-            active.status = Set(new_status.as_str().to_string()); 
+
+        // Al completar la subasta se determina el ganador en la misma transacción
+        if completing {
+            crate::settlement::settle_auction(&txn, &updated, chrono::Utc::now())
+                .await
+                .map_err(AppError::from)?;
         }
-        
-        let updated = active.update(&self.db).await.map_err(|e| Status::internal(format!("DB error: {}", e)))?;
+        txn.commit().await.map_err(AppError::from)?;
         log::info!("Subasta actualizada exitosamente con ID: {}", updated.id);
+
+        if updated.status != previous_status {
+            self.events.publish(events::status_changed(updated.id, updated.status));
+        }
         Ok(Response::new(UpdateAuctionResponse {
             auction: Some(map_model_to_proto(&updated)),
         }))
@@ -378,15 +433,231 @@ impl AuctionService for MyAuctionService {
     async fn delete_auction(
         &self,
         request: Request<DeleteAuctionRequest>,
-    ) -> Result<Response<auction::Empty>, Status> {
+    ) -> Result<Response<DeleteAuctionResponse>, Status> {
+        let identity = crate::auth::caller(&request)?;
         let req = request.into_inner();
         log::info!("Recibida solicitud delete_auction con id={}", req.id);
-        let id = uuid::Uuid::parse_str(&req.id).map_err(|_| Status::invalid_argument("id inválido"))?;
-        AuctionEntity::delete_by_id(id)
-            .exec(&self.db)
+        let id = uuid::Uuid::parse_str(&req.id).map_err(|_| AppError::InvalidId { field: "id" })?;
+
+        // Eliminación lógica: la subasta y sus pujas se conservan para disputas y contabilidad
+        let txn = self.db.begin().await
+            .map_err(AppError::from)?;
+        let Some(model) = find_live_auction(id)
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?
+        else {
+            return Err(AppError::AuctionNotFound.into());
+        };
+        policy::authorize(&identity, Action::DeleteAuction, &model)?;
+        if model.status == AuctionStatus::Active {
+            return Err(AppError::DeleteActiveAuction.into());
+        }
+        let bid_count = BidEntity::find()
+            .filter(crate::models::bid::Column::AuctionId.eq(id))
+            .count(&txn)
+            .await
+            .map_err(AppError::from)?;
+        if bid_count > 0 {
+            return Err(AppError::DeleteWithBids { bid_count }.into());
+        }
+
+        let deleted_at = chrono::Utc::now();
+        let result = AuctionEntity::update_many()
+            .col_expr(crate::models::auction::Column::DeletedAt, Expr::value(deleted_at))
+            .col_expr(crate::models::auction::Column::Version, Expr::col(crate::models::auction::Column::Version).add(1))
+            .filter(crate::models::auction::Column::Id.eq(id))
+            .filter(crate::models::auction::Column::DeletedAt.is_null())
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        if result.rows_affected == 0 {
+            return Err(AppError::AuctionNotFound.into());
+        }
+        txn.commit().await.map_err(AppError::from)?;
+        log::info!("Subasta {} eliminada", id);
+
+        let snapshot = AuctionModel { deleted_at: Some(deleted_at), version: model.version + 1, ..model };
+        Ok(Response::new(DeleteAuctionResponse {
+            auction: Some(map_model_to_proto(&snapshot)),
+        }))
+    }
+
+    async fn restore_auction(
+        &self,
+        request: Request<RestoreAuctionRequest>,
+    ) -> Result<Response<RestoreAuctionResponse>, Status> {
+        let identity = crate::auth::caller(&request)?;
+        let req = request.into_inner();
+        log::info!("Recibida solicitud restore_auction con id={}", req.id);
+        let id = uuid::Uuid::parse_str(&req.id).map_err(|_| AppError::InvalidId { field: "id" })?;
+
+        let Some(model) = AuctionEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?
+        else {
+            return Err(AppError::AuctionNotFound.into());
+        };
+        policy::authorize(&identity, Action::RestoreAuction, &model)?;
+        if model.deleted_at.is_none() {
+            return Err(AppError::AuctionNotDeleted.into());
+        }
+
+        let mut active: AuctionActiveModel = model.into();
+        active.deleted_at = Set(None);
+        let restored = update_auction_versioned(&self.db, active).await?;
+        log::info!("Subasta {} restaurada", id);
+        Ok(Response::new(RestoreAuctionResponse {
+            auction: Some(map_model_to_proto(&restored)),
+        }))
+    }
+
+    async fn get_auction_result(
+        &self,
+        request: Request<GetAuctionResultRequest>,
+    ) -> Result<Response<GetAuctionResultResponse>, Status> {
+        let req = request.into_inner();
+        log::info!("Recibida solicitud get_auction_result para auction_id={}", req.auction_id);
+        let auction_id = Uuid::parse_str(&req.auction_id)
+            .map_err(|_| AppError::InvalidId { field: "auction_id" })?;
+
+        let auction = AuctionEntity::find_by_id(auction_id)
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+        if auction.is_none() {
+            return Err(AppError::AuctionNotFound.into());
+        }
+
+        let result = AuctionResultEntity::find()
+            .filter(crate::models::auction_result::Column::AuctionId.eq(auction_id))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        match result {
+            Some(result) => Ok(Response::new(GetAuctionResultResponse {
+                result: Some(map_result_model_to_proto(&result)),
+            })),
+            None => Err(AppError::AuctionNotClosed.into()),
+        }
+    }
+
+    async fn cancel_auction(
+        &self,
+        request: Request<CancelAuctionRequest>,
+    ) -> Result<Response<CancelAuctionResponse>, Status> {
+        let identity = crate::auth::caller(&request)?;
+        let req = request.into_inner();
+        log::info!("Recibida solicitud cancel_auction con id={} por {}", req.id, req.actor_id);
+        let id = Uuid::parse_str(&req.id).map_err(|_| AppError::InvalidId { field: "id" })?;
+        validate_closure_request(&req.actor_id, &req.reason)?;
+        // El actor registrado en el cierre es el usuario autenticado
+        crate::auth::resolve_user_id(&identity, &req.actor_id, "actor_id")?;
+
+        let txn = self.db.begin().await
+            .map_err(AppError::from)?;
+        let Some(model) = find_live_auction(id)
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?
+        else {
+            return Err(AppError::AuctionNotFound.into());
+        };
+        policy::authorize(&identity, Action::CancelAuction, &model)?;
+
+        let current_status = model.status;
+        if current_status == AuctionStatus::Cancelled {
+            return Err(AppError::AuctionAlreadyCancelled.into());
+        }
+        current_status.check_transition(&AuctionStatus::Cancelled)?;
+
+        // Cancelar una subasta con pujas afecta a los postores: requiere force
+        let bid_count = BidEntity::find()
+            .filter(crate::models::bid::Column::AuctionId.eq(id))
+            .count(&txn)
+            .await
+            .map_err(AppError::from)?;
+        if bid_count > 0 && !req.force {
+            return Err(AppError::CancelRequiresForce { bid_count }.into());
+        }
+
+        let now = chrono::Utc::now();
+        let mut active: AuctionActiveModel = model.into();
+        active.status = Set(AuctionStatus::Cancelled);
+        let updated = update_auction_versioned(&txn, active).await?;
+        let cancelled_bids = crate::settlement::cancel_bids(&txn, id)
+            .await
+            .map_err(AppError::from)?;
+        let closure = record_closure(&txn, id, AuctionStatus::Cancelled, &req.actor_id, &req.reason, now)
+            .await
+            .map_err(AppError::from)?;
+        txn.commit().await.map_err(AppError::from)?;
+        log::info!("Subasta {} cancelada por {}: {}", id, req.actor_id, req.reason);
+
+        self.events.publish(events::status_changed(id, updated.status));
+
+        Ok(Response::new(CancelAuctionResponse {
+            auction: Some(map_model_to_proto(&updated)),
+            closure: Some(map_closure_model_to_proto(&closure)),
+            cancelled_bids: cancelled_bids as i32,
+        }))
+    }
+
+    async fn close_auction(
+        &self,
+        request: Request<CloseAuctionRequest>,
+    ) -> Result<Response<CloseAuctionResponse>, Status> {
+        let identity = crate::auth::caller(&request)?;
+        let req = request.into_inner();
+        log::info!("Recibida solicitud close_auction con id={} por {}", req.id, req.actor_id);
+        let id = Uuid::parse_str(&req.id).map_err(|_| AppError::InvalidId { field: "id" })?;
+        validate_closure_request(&req.actor_id, &req.reason)?;
+        // El actor registrado en el cierre es el usuario autenticado
+        crate::auth::resolve_user_id(&identity, &req.actor_id, "actor_id")?;
+
+        let txn = self.db.begin().await
+            .map_err(AppError::from)?;
+        let Some(model) = find_live_auction(id)
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?
+        else {
+            return Err(AppError::AuctionNotFound.into());
+        };
+        policy::authorize(&identity, Action::CloseAuction, &model)?;
+
+        let current_status = model.status;
+        if current_status == AuctionStatus::Completed {
+            return Err(AppError::AuctionAlreadyClosed.into());
+        }
+        current_status.check_transition(&AuctionStatus::Completed)?;
+
+        // El cierre determina ganador y perdedores en la misma transacción
+        let now = chrono::Utc::now();
+        let mut active: AuctionActiveModel = model.into();
+        active.status = Set(AuctionStatus::Completed);
+        let updated = update_auction_versioned(&txn, active).await?;
+        let result = crate::settlement::settle_auction(&txn, &updated, now)
+            .await
+            .map_err(AppError::from)?;
+        let closure = record_closure(&txn, id, AuctionStatus::Completed, &req.actor_id, &req.reason, now)
             .await
-            .map_err(|e| Status::internal(format!("DB error: {}", e)))?;
-        Ok(Response::new(auction::Empty {}))
+            .map_err(AppError::from)?;
+        txn.commit().await.map_err(AppError::from)?;
+        log::info!("Subasta {} cerrada por {}: {}", id, req.actor_id, req.reason);
+
+        self.events.publish(events::status_changed(id, updated.status));
+
+        Ok(Response::new(CloseAuctionResponse {
+            auction: Some(map_model_to_proto(&updated)),
+            closure: Some(map_closure_model_to_proto(&closure)),
+            result: Some(map_result_model_to_proto(&result)),
+        }))
     }
 
     async fn create_bid(
@@ -394,101 +665,265 @@ impl AuctionService for MyAuctionService {
         request: Request<CreateBidRequest>,
     ) -> Result<Response<CreateBidResponse>, Status> {
         log::info!("Recibida solicitud create_bid");
-        let req = request.into_inner();
-        log::info!("Datos recibidos: auction_id={}, user_id={}, amount={}", req.auction_id, req.user_id, req.amount);
+        // El postor es siempre el usuario autenticado
+        let identity = crate::auth::caller(&request)?;
+        let header_key = idempotency::header_key(&request);
+        let mut req = request.into_inner();
+        req.user_id = crate::auth::resolve_user_id(&identity, &req.user_id, "user_id")?;
+
+        // Un reintento con la misma clave devuelve el resultado original sin volver
+        // a pujar
+        let idempotent = idempotency::resolve_key(std::mem::take(&mut req.idempotency_key), header_key)?
+            .map(|key| IdempotentRequest::new(&req.user_id, Operation::CreateBid, key, &req));
+        if let Some(idempotent) = &idempotent {
+            if let Some(previous) = idempotent.replay(&self.db, chrono::Utc::now()).await? {
+                return Ok(Response::new(previous));
+            }
+        }
+        log::info!("Datos recibidos: auction_id={}, user_id={}, amount={}, max_amount={}",
+            req.auction_id, req.user_id, req.amount, if req.max_amount.is_empty() { "" } else { "<oculto>" });
 
         // Validar que la subasta existe
         let auction_id = Uuid::parse_str(&req.auction_id)
-            .map_err(|_| Status::invalid_argument("auction_id inválido"))?;
-        
-        // Validar user_id como string (no UUID)
-        if req.user_id.is_empty() {
-            return Err(Status::invalid_argument("user_id no puede estar vacío"));
+            .map_err(|_| AppError::InvalidId { field: "auction_id" })?;
+
+        // Validar amount (puja manual) o max_amount (puja automática) como número
+        let is_proxy = !req.max_amount.is_empty();
+        if is_proxy && !req.amount.is_empty() {
+            return Err(AppError::AmountConflict.into());
         }
+        let bid_amount = if is_proxy {
+            validate_numeric_string(&req.max_amount, "max_amount")?
+        } else {
+            validate_numeric_string(&req.amount, "amount")?
+        };
 
-        // Validar amount como número
-        let bid_amount = validate_numeric_string(&req.amount, "amount")?;
+        // Toda la colocación de la puja ocurre en una única transacción: la fila de la
+        // subasta se bloquea (SELECT ... FOR UPDATE en Postgres) para que dos pujas
+        // concurrentes no puedan validar contra el mismo highest_bid. SQLite ignora el
+        // bloqueo de fila, pero serializa las transacciones de escritura.
+        let txn = self.db.begin().await
+            .map_err(AppError::from)?;
 
         // Validar que la subasta esté activa
-        let auction = AuctionEntity::find_by_id(auction_id)
-            .one(&self.db)
+        let auction = find_live_auction(auction_id)
+            .lock_exclusive()
+            .one(&txn)
             .await
-            .map_err(|e| Status::internal(format!("DB error: {}", e)))?;
+            .map_err(AppError::from)?;
         
         let Some(auction_model) = auction else {
-            return Err(Status::not_found("Subasta no encontrada"));
+            return Err(AppError::AuctionNotFound.into());
         };
+        // El vendedor no puede pujar en su propia subasta
+        policy::authorize(&identity, Action::PlaceBid, &auction_model)?;
 
         // Validar que la subasta esté activa usando el enum
-        let current_status = AuctionStatus::from_str(&auction_model.status)?;
-        if current_status != AuctionStatus::Active {
-            return Err(Status::failed_precondition(
-                format!("La subasta debe estar en estado 'active'. Estado actual: '{}'", 
-                    auction_model.status)
-            ));
+        if auction_model.status != AuctionStatus::Active {
+            return Err(AppError::AuctionNotActive { status: auction_model.status.as_str().to_string() }.into());
         }
 
         // Validar que la subasta no haya terminado
-        let now = chrono::Utc::now().naive_utc();
+        let now = chrono::Utc::now();
         if now > auction_model.end_time {
-            return Err(Status::failed_precondition("La subasta ha terminado"));
+            return Err(AppError::AuctionEnded.into());
         }
 
         // Validar que la subasta haya comenzado
         if now < auction_model.start_time {
-            return Err(Status::failed_precondition("La subasta aún no ha comenzado"));
+            return Err(AppError::AuctionNotStarted.into());
         }
 
-        // Validar el monto de la puja
+        // Validar el monto de la puja: al menos el precio base y el incremento
+        // mínimo sobre la puja más alta, y siempre mayor que esta
         let current_highest = auction_model.highest_bid.unwrap_or_default();
-        if bid_amount <= current_highest {
-            return Err(Status::failed_precondition("La puja debe ser mayor que la puja más alta actual"));
+        let min_required = (current_highest + auction_model.min_bid_increment).max(auction_model.base_price);
+        if bid_amount < min_required || bid_amount <= current_highest {
+            return Err(AppError::BidTooLow { min_required }.into());
         }
 
-        if bid_amount < auction_model.base_price {
-            return Err(Status::failed_precondition("La puja debe ser mayor o igual al precio base"));
+        // Puja líder actual y pujas automáticas vigentes de la subasta
+        let leading_bid = BidEntity::find()
+            .filter(crate::models::bid::Column::AuctionId.eq(auction_id))
+            .filter(crate::models::bid::Column::Status.eq(BidStatus::Active))
+            .order_by_desc(crate::models::bid::Column::Amount)
+            .order_by_asc(crate::models::bid::Column::CreatedAt)
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?;
+        let mut proxies = ProxyBidEntity::find()
+            .filter(crate::models::proxy_bid::Column::AuctionId.eq(auction_id))
+            .filter(crate::models::proxy_bid::Column::Status.eq(ProxyBidStatus::Active))
+            .all(&txn)
+            .await
+            .map_err(AppError::from)?;
+
+        let own_proxy = proxies.iter().position(|p| p.user_id == req.user_id);
+        if is_proxy {
+            // Registrar o elevar el máximo oculto del postor
+            let saved = match own_proxy.map(|i| proxies.remove(i)) {
+                Some(existing) => {
+                    let mut active: ProxyBidActiveModel = existing.into();
+                    active.max_amount = Set(bid_amount);
+                    active.created_at = Set(now);
+                    active.update(&txn).await
+                },
+                None => ProxyBidActiveModel {
+                    id: Set(Uuid::new_v4()),
+                    auction_id: Set(auction_id),
+                    user_id: Set(req.user_id.clone()),
+                    max_amount: Set(bid_amount),
+                    created_at: Set(now),
+                    status: Set(ProxyBidStatus::Active),
+                }.insert(&txn).await,
+            }.map_err(AppError::from)?;
+            proxies.push(saved);
+        } else if let Some(i) = own_proxy {
+            // Una puja manual reemplaza la automática propia solo si la supera
+            if proxies[i].max_amount >= bid_amount {
+                return Err(AppError::ProxyMaxNotExceeded.into());
+            }
+            let mut active: ProxyBidActiveModel = proxies.remove(i).into();
+            active.status = Set(ProxyBidStatus::Exhausted);
+            active.update(&txn).await
+                .map_err(AppError::from)?;
         }
 
-        // Validar incremento mínimo
-        let min_required = current_highest + auction_model.min_bid_increment;
-        if bid_amount < min_required {
-            return Err(Status::failed_precondition(
-                format!("La puja debe ser al menos {}", min_required)
-            ));
+        let mut ceilings: Vec<Ceiling> = proxies.iter().map(|p| Ceiling {
+            user_id: p.user_id.clone(),
+            amount: p.max_amount,
+            submitted_at: p.created_at,
+            kind: CeilingKind::Proxy,
+        }).collect();
+        if !is_proxy {
+            ceilings.push(Ceiling {
+                user_id: req.user_id.clone(),
+                amount: bid_amount,
+                submitted_at: now,
+                kind: CeilingKind::NewBid,
+            });
+        }
+        if let Some(leader) = &leading_bid {
+            ceilings.push(Ceiling {
+                user_id: leader.user_id.clone(),
+                amount: leader.amount,
+                submitted_at: leader.created_at,
+                kind: CeilingKind::StandingBid,
+            });
         }
 
-        // Crear la puja
-        let bid = BidActiveModel {
-            id: Set(Uuid::new_v4()),
-            auction_id: Set(auction_id),
-            user_id: Set(req.user_id.clone()),
-            amount: Set(bid_amount),
-            created_at: Set(chrono::Utc::now().naive_utc()),
-            status: Set("active".to_string()),
-        };
+        let resolution = proxy_bidding::resolve(
+            ceilings,
+            leading_bid.as_ref().map(|b| (b.user_id.as_str(), b.amount)),
+            auction_model.base_price,
+            auction_model.min_bid_increment,
+        );
+        log::info!("Resolución de pujas: líder={}, precio={}, pujas registradas={}",
+            resolution.leader, resolution.price, resolution.bids.len());
+
+        // Registrar las pujas visibles en orden; el desfase de microsegundos conserva
+        // el orden cronológico cuando varias se generan en la misma transacción
+        let mut requester_bid = None;
+        let mut inserted_bids = Vec::new();
+        let mut leader_bid_id = leading_bid.as_ref().map(|b| b.id);
+        for (i, planned) in resolution.bids.iter().enumerate() {
+            let bid = BidActiveModel {
+                id: Set(Uuid::new_v4()),
+                auction_id: Set(auction_id),
+                user_id: Set(planned.user_id.clone()),
+                amount: Set(planned.amount),
+                created_at: Set(now + chrono::Duration::microseconds(i as i64)),
+                status: Set(BidStatus::Active),
+                retracted_at: Set(None),
+                retraction_reason: Set(None),
+            };
+            let inserted = bid.insert(&txn).await
+                .map_err(AppError::from)?;
+            if inserted.user_id == resolution.leader {
+                leader_bid_id = Some(inserted.id);
+            }
+            if inserted.user_id == req.user_id {
+                requester_bid = Some(inserted.clone());
+            }
+            inserted_bids.push(inserted);
+        }
+
+        // Solo la puja líder queda activa; el resto pasa a "outbid"
+        if let Some(leader_id) = leader_bid_id {
+            BidEntity::update_many()
+                .col_expr(crate::models::bid::Column::Status, Expr::value(BidStatus::Outbid))
+                .filter(crate::models::bid::Column::AuctionId.eq(auction_id))
+                .filter(crate::models::bid::Column::Status.eq(BidStatus::Active))
+                .filter(crate::models::bid::Column::Id.ne(leader_id))
+                .exec(&txn)
+                .await
+                .map_err(AppError::from)?;
+        }
+        if !resolution.exhausted.is_empty() {
+            ProxyBidEntity::update_many()
+                .col_expr(crate::models::proxy_bid::Column::Status, Expr::value(ProxyBidStatus::Exhausted))
+                .filter(crate::models::proxy_bid::Column::AuctionId.eq(auction_id))
+                .filter(crate::models::proxy_bid::Column::UserId.is_in(resolution.exhausted.clone()))
+                .exec(&txn)
+                .await
+                .map_err(AppError::from)?;
+        }
 
-        let inserted_bid = bid.insert(&self.db).await
-            .map_err(|e| {
-                log::error!("Error al insertar puja: {}", e);
-                Status::internal(format!("DB error: {}", e))
-            })?;
+        // Cierre suave: una puja dentro de la ventana final extiende end_time
+        let end_time_extended = !resolution.bids.is_empty() && should_extend_end_time(&auction_model, now);
+        let mut end_time = auction_model.end_time;
+        if end_time_extended {
+            end_time += chrono::Duration::seconds(auction_model.extension_secs as i64);
+            log::info!("Puja dentro de la ventana de cierre: end_time extendido a {} (extensión {}/{})",
+                end_time, auction_model.extensions_count + 1, auction_model.max_extensions);
+        }
+        let extensions_count = auction_model.extensions_count;
+        let previous_highest = auction_model.highest_bid;
+        let currency = auction_model.currency;
 
         // Actualizar la puja más alta en la subasta
         let mut auction_active: AuctionActiveModel = auction_model.into();
-        auction_active.highest_bid = Set(Some(bid_amount));
+        auction_active.highest_bid = Set(Some(resolution.price));
+        if end_time_extended {
+            auction_active.end_time = Set(end_time);
+            auction_active.extensions_count = Set(extensions_count + 1);
+        }
         
-        auction_active.update(&self.db).await
-            .map_err(|e| {
-                log::error!("Error al actualizar subasta: {}", e);
-                Status::internal(format!("DB error: {}", e))
-            })?;
-
-        log::info!("Puja creada con id {}", inserted_bid.id);
-        let proto_bid = map_bid_model_to_proto(&inserted_bid);
+        update_auction_versioned(&txn, auction_active).await?;
+
+        let response = CreateBidResponse {
+            bid: requester_bid.as_ref().map(|bid| map_bid_model_to_proto(bid, currency)),
+            is_leading: resolution.leader == req.user_id,
+            highest_bid: format_amount(resolution.price, currency),
+            end_time: utc_to_proto_timestamp(&end_time),
+            end_time_extended,
+        };
+        if let Some(previous) = idempotency::commit(txn, &self.db, idempotent.as_ref(), &response, now, self.idempotency_ttl).await? {
+            return Ok(Response::new(previous));
+        }
+
+        if let Some(bid) = &requester_bid {
+            log::info!("Puja creada con id {}", bid.id);
+        }
+
+        // Notificar a los suscriptores de WatchAuction una vez confirmada la transacción
+        for bid in &inserted_bids {
+            let mut event = events::new_event(auction_id, AuctionEventType::BidPlaced);
+            event.bid = Some(map_bid_model_to_proto(bid, currency));
+            self.events.publish(event);
+        }
+        if previous_highest != Some(resolution.price) {
+            let mut event = events::new_event(auction_id, AuctionEventType::HighestBidChanged);
+            event.highest_bid = format_amount(resolution.price, currency);
+            self.events.publish(event);
+        }
+        if end_time_extended {
+            let mut event = events::new_event(auction_id, AuctionEventType::EndTimeExtended);
+            event.end_time = utc_to_proto_timestamp(&end_time);
+            self.events.publish(event);
+        }
         
-        Ok(Response::new(CreateBidResponse {
-            bid: Some(proto_bid),
-        }))
+        Ok(Response::new(response))
     }
 
     async fn list_bids(
@@ -499,15 +934,16 @@ impl AuctionService for MyAuctionService {
         log::info!("Recibida solicitud list_bids para auction_id={}", req.auction_id);
         
         let auction_id = Uuid::parse_str(&req.auction_id)
-            .map_err(|_| Status::invalid_argument("auction_id inválido"))?;
+            .map_err(|_| AppError::InvalidId { field: "auction_id" })?;
 
         let bids = BidEntity::find()
             .filter(crate::models::bid::Column::AuctionId.eq(auction_id))
             .all(&self.db)
             .await
-            .map_err(|e| Status::internal(format!("DB error: {}", e)))?;
+            .map_err(AppError::from)?;
+        let currency = auction_currency(&self.db, auction_id).await?;
 
-        let proto_bids = bids.iter().map(map_bid_model_to_proto).collect();
+        let proto_bids = bids.iter().map(|bid| map_bid_model_to_proto(bid, currency)).collect();
         
         Ok(Response::new(ListBidsResponse {
             bids: proto_bids,
@@ -520,70 +956,285 @@ impl AuctionService for MyAuctionService {
     ) -> Result<Response<GetHighestBidResponse>, Status> {
         let req = request.into_inner();
         let auction_id = Uuid::parse_str(&req.auction_id)
-            .map_err(|_| Status::invalid_argument("auction_id inválido"))?;
+            .map_err(|_| AppError::InvalidId { field: "auction_id" })?;
 
+        // Las pujas retractadas o canceladas ya no cuentan
         let highest_bid = BidEntity::find()
             .filter(crate::models::bid::Column::AuctionId.eq(auction_id))
+            .filter(crate::models::bid::Column::Status.is_not_in([BidStatus::Retracted, BidStatus::Cancelled]))
             .order_by_desc(crate::models::bid::Column::Amount)
             .one(&self.db)
             .await
-            .map_err(|e| Status::internal(format!("DB error: {}", e)))?;
+            .map_err(AppError::from)?;
 
         match highest_bid {
             Some(bid) => Ok(Response::new(GetHighestBidResponse {
-                bid: Some(map_bid_model_to_proto(&bid)),
+                bid: Some(map_bid_model_to_proto(&bid, auction_currency(&self.db, auction_id).await?)),
             })),
-            None => Err(Status::not_found("No hay pujas para esta subasta")),
+            None => Err(AppError::NoBids.into()),
+        }
+    }
+
+    async fn retract_bid(
+        &self,
+        request: Request<RetractBidRequest>,
+    ) -> Result<Response<RetractBidResponse>, Status> {
+        let identity = crate::auth::caller(&request)?;
+        let mut req = request.into_inner();
+        req.user_id = crate::auth::resolve_user_id(&identity, &req.user_id, "user_id")?;
+        log::info!("Recibida solicitud retract_bid: bid_id={}, user_id={}", req.bid_id, req.user_id);
+        let bid_id = Uuid::parse_str(&req.bid_id)
+            .map_err(|_| AppError::InvalidId { field: "bid_id" })?;
+        if req.reason.trim().is_empty() {
+            return Err(AppError::Required { field: "reason" }.into());
+        }
+
+        // La subasta de una puja no cambia, así que se puede buscar antes del bloqueo
+        let Some(unlocked) = BidEntity::find_by_id(bid_id)
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?
+        else {
+            return Err(AppError::BidNotFound.into());
+        };
+
+        // Bloquear la subasta igual que create_bid y el cierre, y volver a leer la
+        // puja dentro de la transacción: otra retractación, una puja nueva o el
+        // cierre pueden haberla cambiado mientras se esperaba el bloqueo
+        let txn = self.db.begin().await
+            .map_err(AppError::from)?;
+        let auction_model = find_live_auction(unlocked.auction_id)
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?
+            .ok_or(AppError::AuctionNotFound)?;
+        let bid = BidEntity::find_by_id(bid_id)
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?
+            .ok_or(AppError::BidNotFound)?;
+        if bid.user_id != req.user_id {
+            return Err(AppError::NotBidOwner.into());
         }
+        if !bid.status.is_standing() {
+            return Err(AppError::BidNotRetractable { status: bid.status.as_str().to_string() }.into());
+        }
+        if auction_model.status != AuctionStatus::Active {
+            return Err(AppError::AuctionNotActive { status: auction_model.status.as_str().to_string() }.into());
+        }
+        let now = chrono::Utc::now();
+        if !self.retraction.allows(bid.created_at, auction_model.end_time, now) {
+            return Err(AppError::RetractionWindowExpired.into());
+        }
+
+        let auction_id = bid.auction_id;
+        let mut active: BidActiveModel = bid.into();
+        active.status = Set(BidStatus::Retracted);
+        active.retracted_at = Set(Some(now));
+        active.retraction_reason = Set(Some(req.reason.trim().to_string()));
+        let retracted = active.update(&txn).await
+            .map_err(AppError::from)?;
+
+        // La puja automática del postor tampoco debe volver a pujar por él
+        ProxyBidEntity::update_many()
+            .col_expr(crate::models::proxy_bid::Column::Status, Expr::value(ProxyBidStatus::Retracted))
+            .filter(crate::models::proxy_bid::Column::AuctionId.eq(auction_id))
+            .filter(crate::models::proxy_bid::Column::UserId.eq(req.user_id.as_str()))
+            .filter(crate::models::proxy_bid::Column::Status.eq(ProxyBidStatus::Active))
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+
+        let leader = crate::retraction::recompute_highest_bid(&txn, auction_id)
+            .await
+            .map_err(AppError::from)?;
+        txn.commit().await.map_err(AppError::from)?;
+        let highest_bid = leader.as_ref().map(|b| b.amount);
+        let currency = auction_model.currency;
+        log::info!("Puja {} retractada; nueva puja más alta: {:?}", retracted.id, highest_bid);
+
+        let mut event = events::new_event(auction_id, AuctionEventType::BidRetracted);
+        event.bid = Some(map_bid_model_to_proto(&retracted, currency));
+        self.events.publish(event);
+        if highest_bid != auction_model.highest_bid {
+            let mut event = events::new_event(auction_id, AuctionEventType::HighestBidChanged);
+            event.highest_bid = highest_bid.map(|p| format_amount(p, currency)).unwrap_or_default();
+            self.events.publish(event);
+        }
+
+        Ok(Response::new(RetractBidResponse {
+            bid: Some(map_bid_model_to_proto(&retracted, currency)),
+            highest_bid: highest_bid.map(|p| format_amount(p, currency)).unwrap_or_default(),
+            leading_bid: leader.as_ref().map(|bid| map_bid_model_to_proto(bid, currency)),
+        }))
+    }
+
+    type WatchAuctionStream = tokio_stream::wrappers::ReceiverStream<Result<AuctionEvent, Status>>;
+
+    async fn watch_auction(
+        &self,
+        request: Request<WatchAuctionRequest>,
+    ) -> Result<Response<Self::WatchAuctionStream>, Status> {
+        let req = request.into_inner();
+        log::info!("Recibida solicitud watch_auction para subasta {}", req.auction_id);
+        let auction_id = Uuid::parse_str(&req.auction_id)
+            .map_err(|_| AppError::InvalidId { field: "auction_id" })?;
+
+        // Suscribirse antes de leer el estado para no perder eventos intermedios
+        let receiver = self.events.receiver();
+        let auction = find_live_auction(auction_id)
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?
+            .ok_or(AppError::AuctionNotFound)?;
+
+        let mut snapshot = events::new_event(auction_id, AuctionEventType::Snapshot);
+        snapshot.auction = Some(map_model_to_proto(&auction));
+        Ok(Response::new(EventBus::watch(auction_id, receiver, snapshot)))
     }
 }
 
 // Función helper para convertir modelo de puja a proto
-fn map_bid_model_to_proto(model: &BidModel) -> auction::Bid {
+fn map_bid_model_to_proto(model: &BidModel, currency: AuctionCurrency) -> auction::Bid {
     auction::Bid {
         id: model.id.to_string(),
         auction_id: model.auction_id.to_string(),
         user_id: model.user_id.to_string(),
-        amount: model.amount.to_string(),
-        created_at: naive_to_proto_timestamp(&model.created_at),
-        status: model.status.clone(),
+        amount: format_amount(model.amount, currency),
+        created_at: utc_to_proto_timestamp(&model.created_at),
+        status: model.status.as_str().to_string(),
+        status_value: bid_status_to_proto(model.status) as i32,
+        retracted_at: model.retracted_at.as_ref().and_then(utc_to_proto_timestamp),
+        retraction_reason: model.retraction_reason.clone().unwrap_or_default(),
     }
 }
 
-fn proto_timestamp_to_naive(ts: &Option<Timestamp>) -> Result<chrono::NaiveDateTime, Status> {
-    let t = ts.as_ref().ok_or(Status::invalid_argument("timestamp faltante"))?;
-    // Usar DateTime::from_timestamp en lugar de NaiveDateTime::from_timestamp_opt
-    Ok(chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32)
-        .ok_or(Status::invalid_argument("timestamp inválido"))?
-        .naive_utc())
+// Monto con los decimales de su moneda. Postgres conserva la escala con la que
+// se guardó el monto, pero SQLite no ("120.00" se lee como 120), así que se
+// fija al responder para que ambas bases entreguen el mismo texto.
+fn format_amount(amount: rust_decimal::Decimal, currency: AuctionCurrency) -> String {
+    let mut amount = amount;
+    amount.rescale(crate::validation::currency_scale(&currency));
+    amount.to_string()
 }
 
-fn naive_to_proto_timestamp(dt: &chrono::NaiveDateTime) -> Option<Timestamp> {
-    let dt_utc = dt.and_utc();
-    Some(Timestamp {
-        seconds: dt_utc.timestamp(),
-        nanos: dt_utc.timestamp_subsec_nanos() as i32,
-    })
+// Moneda de una subasta, para dar formato a los montos de sus pujas. Si la
+// subasta no existe no hay pujas que formatear.
+async fn auction_currency<C: ConnectionTrait>(db: &C, auction_id: Uuid) -> Result<AuctionCurrency, AppError> {
+    let currency = AuctionEntity::find_by_id(auction_id)
+        .select_only()
+        .column(crate::models::auction::Column::Currency)
+        .into_tuple::<AuctionCurrency>()
+        .one(db)
+        .await?;
+    Ok(currency.unwrap_or(AuctionCurrency::USD))
+}
+
+// Subastas no eliminadas; las eliminadas se tratan como inexistentes
+fn find_live_auction(id: Uuid) -> sea_orm::Select<AuctionEntity> {
+    AuctionEntity::find_by_id(id).filter(crate::models::auction::Column::DeletedAt.is_null())
 }
 
-// Función para validar rangos de fechas (útil para frontend)
-fn validate_date_range(start: &chrono::NaiveDateTime, end: &chrono::NaiveDateTime) -> Result<(), Status> {
-    if start >= end {
-        return Err(Status::invalid_argument("La fecha de inicio debe ser anterior a la fecha de fin"));
+// Escribe los cambios de `active` solo si la subasta conserva la versión con la
+// que se leyó e incrementa la versión en la misma sentencia. Si otra escritura
+// se adelantó se responde ABORTED para que el cliente relea y reintente.
+async fn update_auction_versioned<C: ConnectionTrait>(
+    db: &C,
+    mut active: AuctionActiveModel,
+) -> Result<AuctionModel, AppError> {
+    let id = *active.id.as_ref();
+    let expected = *active.version.as_ref();
+    active.version = Set(expected + 1);
+    let result = AuctionEntity::update_many()
+        .set(active)
+        .filter(crate::models::auction::Column::Id.eq(id))
+        .filter(crate::models::auction::Column::Version.eq(expected))
+        .exec(db)
+        .await?;
+    let current = AuctionEntity::find_by_id(id)
+        .one(db)
+        .await?
+        .ok_or(AppError::AuctionNotFound)?;
+    if result.rows_affected == 0 {
+        log::warn!("Conflicto de versión en subasta {}: esperada {}, actual {}", id, expected, current.version);
+        return Err(AppError::VersionConflict { expected, current: current.version });
     }
-    
-    let now = chrono::Utc::now().naive_utc();
-    if start < &now {
-        return Err(Status::invalid_argument("La fecha de inicio no puede ser en el pasado"));
+    Ok(current)
+}
+
+// Quién cierra o cancela una subasta y por qué son obligatorios
+fn validate_closure_request(actor_id: &str, reason: &str) -> Result<(), AppError> {
+    if actor_id.trim().is_empty() {
+        return Err(AppError::Required { field: "actor_id" });
+    }
+    if reason.trim().is_empty() {
+        return Err(AppError::Required { field: "reason" });
     }
-    
     Ok(())
 }
 
+async fn record_closure<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    auction_id: Uuid,
+    action: AuctionStatus,
+    actor_id: &str,
+    reason: &str,
+    closed_at: chrono::DateTime<chrono::Utc>,
+) -> Result<AuctionClosureModel, sea_orm::DbErr> {
+    AuctionClosureActiveModel {
+        id: Set(Uuid::new_v4()),
+        auction_id: Set(auction_id),
+        action: Set(action),
+        actor_id: Set(actor_id.trim().to_string()),
+        reason: Set(reason.trim().to_string()),
+        closed_at: Set(closed_at),
+    }
+    .insert(db)
+    .await
+}
+
+fn map_closure_model_to_proto(model: &AuctionClosureModel) -> auction::AuctionClosure {
+    auction::AuctionClosure {
+        auction_id: model.auction_id.to_string(),
+        action: model.action.as_str().to_string(),
+        actor_id: model.actor_id.clone(),
+        reason: model.reason.clone(),
+        closed_at: utc_to_proto_timestamp(&model.closed_at),
+    }
+}
+
+// Función helper para convertir el resultado de una subasta a proto
+fn map_result_model_to_proto(model: &AuctionResultModel) -> auction::AuctionResult {
+    auction::AuctionResult {
+        auction_id: model.auction_id.to_string(),
+        winning_bid_id: model.winning_bid_id.map(|id| id.to_string()).unwrap_or_default(),
+        winner_user_id: model.winner_user_id.clone().unwrap_or_default(),
+        final_price: model.final_price.map(|p| format_amount(p, model.currency)).unwrap_or_default(),
+        currency: model.currency.as_str().to_string(),
+        currency_value: currency_to_proto(model.currency) as i32,
+        closed_at: utc_to_proto_timestamp(&model.closed_at),
+        has_winner: model.winning_bid_id.is_some(),
+        reserve_met: model.reserve_met,
+    }
+}
+
+fn proto_timestamp_to_utc(ts: &Option<Timestamp>, field: &'static str) -> Result<chrono::DateTime<chrono::Utc>, AppError> {
+    let t = ts.as_ref().ok_or(AppError::Required { field })?;
+    chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32).ok_or(AppError::InvalidTimestamp { field })
+}
+
+fn utc_to_proto_timestamp(dt: &chrono::DateTime<chrono::Utc>) -> Option<Timestamp> {
+    Some(Timestamp {
+        seconds: dt.timestamp(),
+        nanos: dt.timestamp_subsec_nanos() as i32,
+    })
+}
+
 // Función para crear timestamp desde ISO string (útil para recibir fechas desde React)
-fn iso_string_to_timestamp(iso_string: &str) -> Result<Timestamp, Status> {
+pub fn iso_string_to_timestamp(iso_string: &str) -> Result<Timestamp, Status> {
     let dt = chrono::DateTime::parse_from_rfc3339(iso_string)
-        .map_err(|_| Status::invalid_argument("Formato de fecha ISO inválido"))?;
+        .map_err(|_| AppError::InvalidTimestamp { field: "iso_string" })?;
     
     Ok(Timestamp {
         seconds: dt.timestamp(),
@@ -599,99 +1250,290 @@ fn map_model_to_proto(model: &AuctionModel) -> auction::Auction {
         title: model.title.clone(),
         description: model.description.clone().unwrap_or_default(),
         category: model.category.clone(),
-        start_time: naive_to_proto_timestamp(&model.start_time),
-        end_time: naive_to_proto_timestamp(&model.end_time),
-        base_price: model.base_price.to_string(),
-        min_bid_increment: model.min_bid_increment.to_string(),
-        highest_bid: model.highest_bid.map_or("0".to_string(), |hb| hb.to_string()),
-        status: model.status.clone(),
-        currency: model.currency.clone(),
+        start_time: utc_to_proto_timestamp(&model.start_time),
+        end_time: utc_to_proto_timestamp(&model.end_time),
+        base_price: format_amount(model.base_price, model.currency),
+        min_bid_increment: format_amount(model.min_bid_increment, model.currency),
+        highest_bid: model.highest_bid.map_or("0".to_string(), |hb| format_amount(hb, model.currency)),
+        status: model.status.as_str().to_string(),
+        currency: model.currency.as_str().to_string(),
+        status_value: auction_status_to_proto(model.status) as i32,
+        currency_value: currency_to_proto(model.currency) as i32,
         bids: vec![], 
+        // El precio de reserva se omite intencionalmente del mensaje público
+        has_reserve: model.reserve_price.is_some(),
+        reserve_met: is_reserve_met(model),
+        extension_window_seconds: model.extension_window_secs,
+        extension_seconds: model.extension_secs,
+        max_extensions: model.max_extensions,
+        extensions_count: model.extensions_count,
+        deleted_at: model.deleted_at.as_ref().and_then(utc_to_proto_timestamp),
+        version: model.version,
+    }
+}
+
+// La reserva se considera alcanzada si no existe o si la puja más alta la iguala
+fn is_reserve_met(model: &AuctionModel) -> bool {
+    match model.reserve_price {
+        Some(reserve) => model.highest_bid.is_some_and(|hb| hb >= reserve),
+        None => true,
     }
 }
 
-fn map_model_to_proto_with_bids(model: &AuctionModel, bids: &Vec<BidModel>) -> auction::Auction {
+fn map_model_to_proto_with_bids(model: &AuctionModel, bids: &[BidModel]) -> auction::Auction {
     let mut proto_auction = map_model_to_proto(model);
-    proto_auction.bids = bids.iter().map(map_bid_model_to_proto).collect();
+    proto_auction.bids = bids.iter().map(|bid| map_bid_model_to_proto(bid, model.currency)).collect();
     proto_auction
 }
 
 pub async fn start_grpc_server() -> Result<(), Box<dyn std::error::Error>> {
     let db = crate::db::connect().await;
-    let service = MyAuctionService { db };
-let addr = std::env::var("GRPC_ADDRESS").unwrap_or_else(|_| "0.0.0.0:50052".to_string());
+    let events = EventBus::new(crate::events::capacity_from_env());
+
+    // Tarea en segundo plano que activa y cierra subastas según sus fechas
+    crate::scheduler::spawn_lifecycle_scheduler(
+        db.clone(),
+        events.clone(),
+        std::sync::Arc::new(crate::scheduler::SystemClock),
+        crate::scheduler::tick_interval_from_env(),
+    );
+
+    let service = MyAuctionService::new(db, events)
+        .with_retraction_policy(RetractionPolicy::from_env())
+        .with_idempotency_ttl(idempotency::ttl_from_env());
+    // Todas las llamadas exigen un JWT válido; sin configuración el servidor no arranca
+    let auth = AuthInterceptor::new(JwtVerifier::from_env()?);
+    let addr = std::env::var("GRPC_ADDRESS").unwrap_or_else(|_| "0.0.0.0:50052".to_string());
     let addr = addr.parse().unwrap();
     log::info!("Servidor gRPC escuchando en {}", addr);
+    // El límite de pujas va dentro del interceptor para conocer al llamador
+    let limited = RateLimitService::new(AuctionServiceServer::new(service), BidRateLimiter::from_env());
     Server::builder()
-        .add_service(AuctionServiceServer::new(service))
+        .add_service(LocaleService::new(InterceptedService::new(limited, auth), Locale::fallback_from_env()))
         .serve(addr)
         .await?;
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use sea_orm::{Database, DatabaseConnection, Statement, ConnectionTrait};
-    use tonic::Request;
-
-    async fn setup_test_db() -> DatabaseConnection {
-        let db = Database::connect("sqlite::memory:").await.unwrap();
-        
-        // Crear tabla auction con status y currency
-        db.execute(Statement::from_string(
-            db.get_database_backend(),
-            r#"
-CREATE TABLE auction (
-    id TEXT PRIMARY KEY,
-    user_id TEXT NOT NULL,
-    item_id TEXT NOT NULL,
-    title TEXT NOT NULL,
-    description TEXT,
-    category TEXT NOT NULL,
-    start_time TEXT NOT NULL,
-    end_time TEXT NOT NULL,
-    base_price NUMERIC NOT NULL,
-    min_bid_increment NUMERIC NOT NULL,
-    highest_bid NUMERIC NOT NULL,
-    status TEXT NOT NULL DEFAULT 'pending',
-    currency TEXT NOT NULL DEFAULT 'USD'
-);
-            "#.to_owned(),
-        )).await.unwrap();
-
-        // Crear tabla bid
-        db.execute(Statement::from_string(
-            db.get_database_backend(),
-            r#"
-CREATE TABLE bid (
-    id TEXT PRIMARY KEY,
-    auction_id TEXT NOT NULL,
-    user_id TEXT NOT NULL,
-    amount NUMERIC NOT NULL,
-    created_at TEXT NOT NULL,
-    status TEXT NOT NULL,
-    FOREIGN KEY (auction_id) REFERENCES auction (id)
-);
-            "#.to_owned(),
-        )).await.unwrap();
-        
-        db
+// Indica si una puja recibida en `now` debe extender el cierre de la subasta
+fn should_extend_end_time(model: &AuctionModel, now: chrono::DateTime<chrono::Utc>) -> bool {
+    if model.extension_window_secs <= 0 || model.extension_secs <= 0 {
+        return false;
     }
-
-    async fn setup_service() -> MyAuctionService {
-        let db = setup_test_db().await;
-        MyAuctionService { db }
+    if model.extensions_count >= model.max_extensions {
+        return false;
+    }
+    now >= model.end_time - chrono::Duration::seconds(model.extension_window_secs as i64)
+}
+
+// Función helper para validar que un string representa un número válido
+fn validate_numeric_string(value: &str, field_name: &'static str) -> Result<rust_decimal::Decimal, AppError> {
+    if value.is_empty() {
+        return Err(AppError::Required { field: field_name });
+    }
+    
+    value.parse::<rust_decimal::Decimal>()
+        .map_err(|e| {
+            log::error!("Error al parsear {} '{}': {}", field_name, value, e);
+            AppError::InvalidNumber { field: field_name }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::setup_test_db;
+    use tonic::Request;
+
+    // Los tests llaman a los handlers sin pasar por el interceptor: la identidad
+    // verificada se agrega directamente, tomada del user_id del mensaje
+    trait Caller {
+        fn caller_id(&self) -> &str;
+    }
+
+    impl Caller for CreateAuctionRequest {
+        fn caller_id(&self) -> &str {
+            &self.user_id
+        }
+    }
+
+    impl Caller for CreateBidRequest {
+        fn caller_id(&self) -> &str {
+            &self.user_id
+        }
+    }
+
+    impl Caller for RetractBidRequest {
+        fn caller_id(&self) -> &str {
+            &self.user_id
+        }
+    }
+
+    fn signed<T: Caller>(message: T) -> Request<T> {
+        let user_id = message.caller_id().to_string();
+        as_user(&user_id, message)
+    }
+
+    fn as_user<T>(user_id: &str, message: T) -> Request<T> {
+        let mut request = Request::new(message);
+        request.extensions_mut().insert(crate::auth::Identity { user_id: user_id.to_string(), roles: Vec::new() });
+        request
+    }
+
+    // Administrador con el mismo actor_id que usan los tests de cierre
+    fn as_admin<T>(message: T) -> Request<T> {
+        let mut request = Request::new(message);
+        let roles = vec![crate::policy::ADMIN_ROLE.to_string()];
+        request.extensions_mut().insert(crate::auth::Identity { user_id: "admin".to_string(), roles });
+        request
+    }
+
+    async fn setup_service() -> MyAuctionService {
+        let db = setup_test_db().await;
+        MyAuctionService::new(db, EventBus::default())
+    }
+
+    // Crea una subasta pendiente que comienza en 100 segundos
+    async fn create_pending_auction(service: &MyAuctionService) -> String {
+        let auction_req = CreateAuctionRequest {
+            user_id: uuid::Uuid::new_v4().to_string(),
+            item_id: uuid::Uuid::new_v4().to_string(),
+            title: "Test Auction".to_string(),
+            description: "desc".to_string(),
+            category: "Electronics".to_string(),
+            start_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 100, nanos: 0 }),
+            end_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 3600, nanos: 0 }),
+            base_price: "100.00".to_string(),
+            min_bid_increment: "10.00".to_string(),
+            highest_bid: "".to_string(),
+            reserve_price: "".to_string(),
+            extension_window_seconds: 0,
+            extension_seconds: 0,
+            max_extensions: 0,
+            currency: "USD".to_string(),
+            idempotency_key: "".to_string(),
+            currency_value: 0,
+        };
+        service.create_auction(signed(auction_req)).await.unwrap()
+            .into_inner().auction.unwrap().id
+    }
+
+    // Crea una subasta y la activa, dejándola lista para recibir pujas
+    async fn create_active_auction(service: &MyAuctionService) -> String {
+        let auction_id = create_pending_auction(service).await;
+        let activate_req = UpdateAuctionRequest {
+            id: auction_id.clone(),
+            status: "active".to_string(),
+            ..Default::default()
+        };
+        service.update_auction(as_admin(activate_req)).await.unwrap();
+        auction_id
     }
 
     #[tokio::test]
     async fn test_list_auctions_empty() {
         let service = setup_service().await;
-        let req = Request::new(ListAuctionsRequest {});
+        let req = Request::new(ListAuctionsRequest::default());
         let response = service.list_auctions(req).await.unwrap().into_inner();
         assert_eq!(response.auctions.len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_watch_auction_streams_bids_and_status() {
+        use tokio_stream::StreamExt;
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+
+        let req = WatchAuctionRequest { auction_id: auction_id.clone() };
+        let mut stream = service.watch_auction(Request::new(req)).await.unwrap().into_inner();
+        let snapshot = stream.next().await.unwrap().unwrap();
+        assert_eq!(snapshot.r#type, AuctionEventType::Snapshot as i32);
+        assert_eq!(snapshot.auction.unwrap().status, "active");
+
+        let bid_req = CreateBidRequest {
+            auction_id: auction_id.clone(),
+            user_id: "bidder".to_string(),
+            amount: "150".to_string(),
+            max_amount: "".to_string(),
+            idempotency_key: "".to_string(),
+        };
+        service.create_bid(signed(bid_req)).await.unwrap();
+        let cancel_req = UpdateAuctionRequest {
+            id: auction_id.clone(),
+            status: "cancelled".to_string(),
+            ..Default::default()
+        };
+        service.update_auction(as_admin(cancel_req)).await.unwrap();
+
+        let events: Vec<AuctionEvent> = stream.map(|e| e.unwrap()).collect().await;
+        let types: Vec<i32> = events.iter().map(|e| e.r#type).collect();
+        assert_eq!(types, vec![
+            AuctionEventType::BidPlaced as i32,
+            AuctionEventType::HighestBidChanged as i32,
+            AuctionEventType::StatusChanged as i32,
+        ]);
+        assert_eq!(events[0].bid.as_ref().unwrap().user_id, "bidder");
+        assert_eq!(events[1].highest_bid.parse::<f64>().unwrap(), 150.0);
+        assert_eq!(events[2].status, "cancelled");
+    }
+
+    #[tokio::test]
+    async fn test_watch_auction_not_found() {
+        let service = setup_service().await;
+        let req = WatchAuctionRequest { auction_id: uuid::Uuid::new_v4().to_string() };
+        let err = service.watch_auction(Request::new(req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_list_auctions_loads_bids_in_one_query() {
+        let mut db = setup_test_db().await;
+        let queries = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = queries.clone();
+        db.set_metric_callback(move |_| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        });
+        let service = MyAuctionService::new(db, EventBus::default());
+
+        let mut auction_ids = Vec::new();
+        for _ in 0..3 {
+            let auction_id = create_active_auction(&service).await;
+            for amount in ["120", "130", "140"] {
+                let bid_req = CreateBidRequest {
+                    auction_id: auction_id.clone(),
+                    user_id: uuid::Uuid::new_v4().to_string(),
+                    amount: amount.to_string(),
+                    max_amount: "".to_string(),
+                    idempotency_key: "".to_string(),
+                };
+                service.create_bid(signed(bid_req)).await.unwrap();
+            }
+            auction_ids.push(auction_id);
+        }
+
+        // Una consulta para la página y otra para todas sus pujas
+        queries.store(0, std::sync::atomic::Ordering::SeqCst);
+        let req = ListAuctionsRequest { include_bids: true, max_bids_per_auction: 2, ..Default::default() };
+        let response = service.list_auctions(Request::new(req)).await.unwrap().into_inner();
+        assert_eq!(queries.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(response.auctions.len(), 3);
+        for auction in &response.auctions {
+            assert!(auction_ids.contains(&auction.id));
+            let amounts: Vec<_> = auction.bids.iter().map(|b| b.amount.parse::<f64>().unwrap()).collect();
+            assert_eq!(amounts, vec![140.0, 130.0]);
+        }
+
+        // Sin include_bids no se consultan las pujas
+        queries.store(0, std::sync::atomic::Ordering::SeqCst);
+        let response = service.list_auctions(Request::new(ListAuctionsRequest::default())).await.unwrap().into_inner();
+        assert_eq!(queries.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert!(response.auctions.iter().all(|a| a.bids.is_empty()));
+
+        let req = ListAuctionsRequest { include_bids: true, max_bids_per_auction: -1, ..Default::default() };
+        let err = service.list_auctions(Request::new(req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
     #[tokio::test]
     async fn test_create_auction_ok() {
         let service = setup_service().await;
@@ -701,14 +1543,20 @@ CREATE TABLE bid (
             title: "Test Auction".to_string(),
             description: "desc".to_string(),
             category: "Electronics".to_string(),
-            start_time: Some(prost_types::Timestamp { seconds: 1_600_000_000, nanos: 0 }),
-            end_time: Some(prost_types::Timestamp { seconds: 1_600_000_100, nanos: 0 }),
+            start_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 100, nanos: 0 }),
+            end_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 3600, nanos: 0 }),
             base_price: "100.00".to_string(),
             min_bid_increment: "10.00".to_string(),
             highest_bid: "".to_string(),
+            reserve_price: "".to_string(),
+            extension_window_seconds: 0,
+            extension_seconds: 0,
+            max_extensions: 0,
             currency: "EUR".to_string(), // Prueba con moneda diferente
+            idempotency_key: "".to_string(),
+            currency_value: 0,
         };
-        let response = service.create_auction(Request::new(req)).await.unwrap().into_inner();
+        let response = service.create_auction(signed(req)).await.unwrap().into_inner();
         let auction = response.auction.unwrap();
         assert_eq!(auction.title, "Test Auction");
         assert_eq!(auction.category, "Electronics");
@@ -725,78 +1573,162 @@ CREATE TABLE bid (
             title: "Test Auction".to_string(),
             description: "desc".to_string(),
             category: "Electronics".to_string(),
-            start_time: Some(prost_types::Timestamp { seconds: 1_600_000_000, nanos: 0 }),
-            end_time: Some(prost_types::Timestamp { seconds: 1_600_000_100, nanos: 0 }),
+            start_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 100, nanos: 0 }),
+            end_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 3600, nanos: 0 }),
             base_price: "100.00".to_string(),
             min_bid_increment: "10.00".to_string(),
             highest_bid: "".to_string(),
+            reserve_price: "".to_string(),
+            extension_window_seconds: 0,
+            extension_seconds: 0,
+            max_extensions: 0,
             currency: "".to_string(), // Sin especificar moneda
+            idempotency_key: "".to_string(),
+            currency_value: 0,
         };
-        let response = service.create_auction(Request::new(req)).await.unwrap().into_inner();
+        let response = service.create_auction(signed(req)).await.unwrap().into_inner();
         let auction = response.auction.unwrap();
         assert_eq!(auction.currency, "USD"); // Debe usar USD por defecto
     }
 
     #[tokio::test]
-    async fn test_auction_status_validation() {
-        // Probar que el enum funciona correctamente
-        assert_eq!(AuctionStatus::Pending.as_str(), "pending");
-        assert_eq!(AuctionStatus::Active.as_str(), "active");
-        assert_eq!(AuctionStatus::Completed.as_str(), "completed");
-        assert_eq!(AuctionStatus::Cancelled.as_str(), "cancelled");
-        
-        // Probar conversión desde string
-        assert!(AuctionStatus::from_str("pending").is_ok());
-        assert!(AuctionStatus::from_str("active").is_ok());
-        assert!(AuctionStatus::from_str("completed").is_ok());
-        assert!(AuctionStatus::from_str("cancelled").is_ok());
-        assert!(AuctionStatus::from_str("invalid").is_err());
+    async fn test_typed_status_and_currency_on_the_wire() {
+        let service = setup_service().await;
+        let req = CreateAuctionRequest {
+            user_id: "seller".to_string(),
+            item_id: "item".to_string(),
+            title: "Test Auction".to_string(),
+            category: "Electronics".to_string(),
+            start_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 100, nanos: 0 }),
+            end_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 3600, nanos: 0 }),
+            base_price: "1000".to_string(),
+            min_bid_increment: "100".to_string(),
+            currency_value: auction::Currency::Clp as i32,
+            ..Default::default()
+        };
+        let created = service.create_auction(signed(req.clone())).await.unwrap().into_inner().auction.unwrap();
+        // Las respuestas llevan el texto y el valor tipado
+        assert_eq!(created.currency, "CLP");
+        assert_eq!(created.currency_value, auction::Currency::Clp as i32);
+        assert_eq!(created.status, "pending");
+        assert_eq!(created.status_value, auction::AuctionStatus::Pending as i32);
+
+        // Texto y valor tipado contradictorios
+        let conflicting = CreateAuctionRequest { currency: "USD".to_string(), ..req };
+        let err = service.create_auction(signed(conflicting)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+        let update_req = UpdateAuctionRequest {
+            id: created.id.clone(),
+            status_value: auction::AuctionStatus::Active as i32,
+            update_mask: mask(&["status_value"]),
+            ..Default::default()
+        };
+        let updated = service.update_auction(as_user("seller", update_req)).await.unwrap().into_inner().auction.unwrap();
+        assert_eq!(updated.status, "active");
+        assert_eq!(updated.status_value, auction::AuctionStatus::Active as i32);
+
+        let list_req = ListAuctionsRequest {
+            status_value: auction::AuctionStatus::Active as i32,
+            currency_value: auction::Currency::Clp as i32,
+            ..Default::default()
+        };
+        let listed = service.list_auctions(Request::new(list_req)).await.unwrap().into_inner().auctions;
+        assert_eq!(listed.iter().map(|a| a.id.as_str()).collect::<Vec<_>>(), vec![created.id.as_str()]);
+        let list_req = ListAuctionsRequest { currency: "usd".to_string(), ..Default::default() };
+        assert!(service.list_auctions(Request::new(list_req)).await.unwrap().into_inner().auctions.is_empty());
     }
 
     #[tokio::test]
-    async fn test_currency_validation() {
-        // Probar que el enum funciona correctamente
-        assert_eq!(AuctionCurrency::USD.as_str(), "USD");
-        assert_eq!(AuctionCurrency::EUR.as_str(), "EUR");
-        assert_eq!(AuctionCurrency::CLP.as_str(), "CLP");
-        
-        // Probar conversión desde string
-        assert!(AuctionCurrency::from_str("USD").is_ok());
-        assert!(AuctionCurrency::from_str("eur").is_ok()); // Case insensitive
-        assert!(AuctionCurrency::from_str("clp").is_ok());
-        assert!(AuctionCurrency::from_str("INVALID").is_err());
+    async fn test_update_auction_rejects_invalid_transition() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        let complete_req = UpdateAuctionRequest {
+            id: auction_id.clone(),
+            status: "completed".to_string(),
+            ..Default::default()
+        };
+        service.update_auction(as_admin(complete_req)).await.unwrap();
+
+        let reopen_req = UpdateAuctionRequest {
+            id: auction_id.clone(),
+            status: "active".to_string(),
+            ..Default::default()
+        };
+        let err = service.update_auction(as_admin(reopen_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+        assert!(err.message().contains("'completed' -> 'active'"));
+        assert!(err.message().contains("ninguna"));
+
+        let get_req = GetAuctionRequest { id: auction_id, ..Default::default() };
+        let auction = service.get_auction(Request::new(get_req)).await.unwrap().into_inner().auction.unwrap();
+        assert_eq!(auction.status, "completed");
+    }
+
+    #[tokio::test]
+    async fn test_activation_keeps_past_start_time() {
+        let service = setup_service().await;
+        // Subasta pendiente cuyo start_time ya pasó (el scheduler aún no la activó)
+        let start = chrono::DateTime::from_timestamp(chrono::Utc::now().timestamp() - 600, 0).unwrap();
+        let pending = AuctionActiveModel {
+            id: Set(Uuid::new_v4()),
+            user_id: Set("seller".to_string()),
+            item_id: Set("item".to_string()),
+            title: Set("Test".to_string()),
+            description: Set(None),
+            category: Set("Electronics".to_string()),
+            start_time: Set(start),
+            end_time: Set(start + chrono::Duration::hours(1)),
+            base_price: Set(rust_decimal::Decimal::from(100)),
+            min_bid_increment: Set(rust_decimal::Decimal::from(10)),
+            highest_bid: Set(None),
+            status: Set(AuctionStatus::Pending),
+            currency: Set(AuctionCurrency::USD),
+            reserve_price: Set(None),
+            extension_window_secs: Set(0),
+            extension_secs: Set(0),
+            max_extensions: Set(0),
+            extensions_count: Set(0),
+            deleted_at: Set(None),
+            version: Set(1),
+        }
+        .insert(&service.db)
+        .await
+        .unwrap();
+
+        let activate_req = UpdateAuctionRequest { id: pending.id.to_string(), status: "active".to_string(), ..Default::default() };
+        let auction = service.update_auction(as_admin(activate_req)).await.unwrap().into_inner().auction.unwrap();
+        assert_eq!(auction.status, "active");
+        assert_eq!(auction.start_time.unwrap().seconds, start.timestamp());
+    }
+
+    #[tokio::test]
+    async fn test_early_activation_moves_start_time_to_now() {
+        let service = setup_service().await;
+        let before = chrono::Utc::now().timestamp();
+        let auction_id = create_active_auction(&service).await;
+
+        let get_req = GetAuctionRequest { id: auction_id, ..Default::default() };
+        let auction = service.get_auction(Request::new(get_req)).await.unwrap().into_inner().auction.unwrap();
+        let start = auction.start_time.unwrap().seconds;
+        assert!(start >= before && start <= chrono::Utc::now().timestamp());
     }
 
     #[tokio::test]
     async fn test_create_bid_ok() {
         let service = setup_service().await;
-        
-        // Primero crear una subasta
-        let auction_req = CreateAuctionRequest {
-            user_id: uuid::Uuid::new_v4().to_string(),
-            item_id: uuid::Uuid::new_v4().to_string(),
-            title: "Test Auction".to_string(),
-            description: "desc".to_string(),
-            category: "Electronics".to_string(),
-            start_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 100, nanos: 0 }),
-            end_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 3600, nanos: 0 }),
-            base_price: "100.00".to_string(),
-            min_bid_increment: "10.00".to_string(),
-            highest_bid: "".to_string(),
-            currency: "USD".to_string(),
-        };
-        
-        let auction_response = service.create_auction(Request::new(auction_req)).await.unwrap().into_inner();
-        let auction_id = auction_response.auction.unwrap().id;
+        let auction_id = create_active_auction(&service).await;
 
         // Crear una puja
         let bid_req = CreateBidRequest {
             auction_id: auction_id.clone(),
             user_id: uuid::Uuid::new_v4().to_string(),
             amount: "120.00".to_string(),
+            max_amount: "".to_string(),
+            idempotency_key: "".to_string(),
         };
 
-        let bid_response = service.create_bid(Request::new(bid_req)).await.unwrap().into_inner();
+        let bid_response = service.create_bid(signed(bid_req)).await.unwrap().into_inner();
         let bid = bid_response.bid.unwrap();
         
         assert_eq!(bid.auction_id, auction_id);
@@ -805,26 +1737,398 @@ CREATE TABLE bid (
     }
 
     #[tokio::test]
-    async fn test_get_auction_with_bids() {
+    async fn test_low_bid_reports_min_required() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        place_bid(&service, &auction_id, "alice", "120.00").await;
+
+        let err = service.create_bid(signed(CreateBidRequest {
+            auction_id: auction_id.clone(),
+            user_id: "bob".to_string(),
+            amount: "125.00".to_string(),
+            max_amount: "".to_string(),
+            idempotency_key: "".to_string(),
+        })).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+        let info = crate::error::error_info(&err).unwrap();
+        assert_eq!(info.reason, "BID_TOO_LOW");
+        assert_eq!(info.metadata["min_required"].parse::<f64>().unwrap(), 130.0);
+
+        // Un monto mal formado indica el campo afectado
+        let err = service.create_bid(signed(CreateBidRequest {
+            auction_id,
+            user_id: "bob".to_string(),
+            amount: "abc".to_string(),
+            max_amount: "".to_string(),
+            idempotency_key: "".to_string(),
+        })).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert_eq!(crate::error::bad_request(&err).unwrap().field_violations[0].field, "amount");
+    }
+
+    #[tokio::test]
+    async fn test_create_bid_uses_token_identity() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        let bid = |user_id: &str| CreateBidRequest {
+            auction_id: auction_id.clone(),
+            user_id: user_id.to_string(),
+            amount: "120.00".to_string(),
+            max_amount: "".to_string(),
+            idempotency_key: "".to_string(),
+        };
+
+        // Sin identidad verificada no se puede pujar
+        let err = service.create_bid(Request::new(bid("alice"))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+        // El user_id del mensaje no puede suplantar al del token
+        let err = service.create_bid(as_user("mallory", bid("alice"))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+
+        // Sin user_id en el mensaje se usa el del token
+        let res = service.create_bid(as_user("alice", bid(""))).await.unwrap().into_inner();
+        assert_eq!(res.bid.unwrap().user_id, "alice");
+    }
+
+    #[tokio::test]
+    async fn test_only_owner_or_admin_can_mutate_and_sellers_cannot_bid() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        let get_req = GetAuctionRequest { id: auction_id.clone(), ..Default::default() };
+        let owner = service.get_auction(Request::new(get_req)).await.unwrap().into_inner().auction.unwrap().user_id;
+        let rename = |title: &str| UpdateAuctionRequest {
+            id: auction_id.clone(),
+            title: title.to_string(),
+            ..Default::default()
+        };
+
+        let err = service.update_auction(as_user("mallory", rename("Robada"))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+        assert_eq!(crate::error::error_info(&err).unwrap().reason, "NOT_AUCTION_OWNER");
+        let err = service.cancel_auction(as_user("mallory", CancelAuctionRequest {
+            id: auction_id.clone(),
+            actor_id: "mallory".to_string(),
+            reason: "sabotaje".to_string(),
+            force: true,
+        })).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+
+        let updated = service.update_auction(as_user(&owner, rename("Nuevo título"))).await.unwrap();
+        assert_eq!(updated.into_inner().auction.unwrap().title, "Nuevo título");
+
+        let err = service.create_bid(as_user(&owner, CreateBidRequest {
+            auction_id: auction_id.clone(),
+            user_id: owner.clone(),
+            amount: "150.00".to_string(),
+            max_amount: "".to_string(),
+            idempotency_key: "".to_string(),
+        })).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+        assert_eq!(crate::error::error_info(&err).unwrap().reason, "SELF_BIDDING");
+    }
+
+    // Envía un CreateBid por la capa de límite, como lo haría el servidor
+    async fn call_limited<S>(stack: &mut S, user_id: &str, message: &CreateBidRequest) -> tonic::codegen::http::Response<tonic::body::BoxBody>
+    where
+        S: tonic::codegen::Service<tonic::codegen::http::Request<tonic::transport::Body>, Response = tonic::codegen::http::Response<tonic::body::BoxBody>, Error = std::convert::Infallible>,
+    {
+        use prost::Message;
+        let encoded = message.encode_to_vec();
+        let mut frame = vec![0u8];
+        frame.extend_from_slice(&(encoded.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&encoded);
+        let request = tonic::codegen::http::Request::builder()
+            .method("POST")
+            .uri("/auction.AuctionService/CreateBid")
+            .header("content-type", "application/grpc")
+            .extension(crate::auth::Identity { user_id: user_id.to_string(), roles: Vec::new() })
+            .body(tonic::transport::Body::from(frame))
+            .unwrap();
+        std::future::poll_fn(|cx| stack.poll_ready(cx)).await.unwrap();
+        stack.call(request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_bid_rate_limited_with_retry_after() {
+        let clock = std::sync::Arc::new(crate::test_utils::FakeClock::new(chrono::Utc::now()));
+        let limit = crate::rate_limit::RateLimit { burst: 1, per_second: 0.25 };
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        let mut stack = RateLimitService::new(
+            AuctionServiceServer::new(service.clone()),
+            BidRateLimiter::new(Some(limit), None, clock.clone()),
+        );
+
+        let bid_req = CreateBidRequest {
+            auction_id: auction_id.clone(),
+            user_id: "alice".to_string(),
+            amount: "120.00".to_string(),
+            max_amount: "".to_string(),
+            idempotency_key: "".to_string(),
+        };
+        let response = call_limited(&mut stack, "alice", &bid_req).await;
+        assert!(Status::from_header_map(response.headers()).is_none());
+
+        let bid_req = CreateBidRequest { amount: "130.00".to_string(), ..bid_req };
+        let response = call_limited(&mut stack, "alice", &bid_req).await;
+        let err = Status::from_header_map(response.headers()).unwrap();
+        assert_eq!(err.code(), tonic::Code::ResourceExhausted);
+        assert_eq!(response.headers().get("retry-after").unwrap(), "4");
+        let info = crate::error::error_info(&err).unwrap();
+        assert_eq!(info.reason, "RATE_LIMITED");
+        assert_eq!(info.metadata["scope"], "user");
+        let bid_count = BidEntity::find()
+            .filter(crate::models::bid::Column::AuctionId.eq(Uuid::parse_str(&auction_id).unwrap()))
+            .count(&service.db)
+            .await
+            .unwrap();
+        assert_eq!(bid_count, 1);
+
+        clock.advance(chrono::Duration::seconds(4));
+        let response = call_limited(&mut stack, "alice", &bid_req).await;
+        assert!(Status::from_header_map(response.headers()).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_create_bid_retry_with_idempotency_key_returns_original() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        let bid_req = CreateBidRequest {
+            auction_id: auction_id.clone(),
+            user_id: "alice".to_string(),
+            amount: "120.00".to_string(),
+            max_amount: "".to_string(),
+            idempotency_key: "puja-1".to_string(),
+        };
+        let first = service.create_bid(signed(bid_req.clone())).await.unwrap().into_inner();
+
+        // El reintento puede traer la clave en la metadata en lugar del mensaje
+        let mut retry = signed(CreateBidRequest { idempotency_key: "".to_string(), ..bid_req.clone() });
+        retry.metadata_mut().insert(idempotency::HEADER, "puja-1".parse().unwrap());
+        let replayed = service.create_bid(retry).await.unwrap().into_inner();
+        assert_eq!(replayed, first);
+        let bid_count = BidEntity::find()
+            .filter(crate::models::bid::Column::AuctionId.eq(Uuid::parse_str(&auction_id).unwrap()))
+            .count(&service.db)
+            .await
+            .unwrap();
+        assert_eq!(bid_count, 1);
+
+        // La misma clave con otro monto se rechaza
+        let reused = CreateBidRequest { amount: "200.00".to_string(), ..bid_req };
+        let err = service.create_bid(signed(reused)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert_eq!(crate::error::error_info(&err).unwrap().reason, "IDEMPOTENCY_KEY_REUSED");
+    }
+
+    fn mask(paths: &[&str]) -> Option<prost_types::FieldMask> {
+        Some(prost_types::FieldMask { paths: paths.iter().map(|p| p.to_string()).collect() })
+    }
+
+    #[tokio::test]
+    async fn test_update_mask_clears_description_and_keeps_other_fields() {
+        let service = setup_service().await;
+        let auction_id = create_pending_auction(&service).await;
+
+        // title no está en la máscara: su valor vacío no cuenta
+        let clear_req = UpdateAuctionRequest {
+            id: auction_id.clone(),
+            update_mask: mask(&["description", "reserve_price"]),
+            ..Default::default()
+        };
+        let auction = service.update_auction(as_admin(clear_req)).await.unwrap().into_inner().auction.unwrap();
+        assert_eq!(auction.description, "");
+        assert_eq!(auction.title, "Test Auction");
+        assert!(!auction.has_reserve);
+
+        let stored = AuctionEntity::find_by_id(Uuid::parse_str(&auction_id).unwrap())
+            .one(&service.db).await.unwrap().unwrap();
+        assert_eq!(stored.description, None);
+
+        // Con la máscara, un título vacío es un error en lugar de "sin cambios"
+        let empty_title = UpdateAuctionRequest { id: auction_id.clone(), update_mask: mask(&["title"]), ..Default::default() };
+        let err = service.update_auction(as_admin(empty_title)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_immutable_fields() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+
+        let masked = UpdateAuctionRequest {
+            id: auction_id.clone(),
+            highest_bid: "999".to_string(),
+            update_mask: mask(&["highest_bid"]),
+            ..Default::default()
+        };
+        let err = service.update_auction(as_admin(masked)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert_eq!(crate::error::error_info(&err).unwrap().reason, "IMMUTABLE_FIELD");
+        assert_eq!(crate::error::bad_request(&err).unwrap().field_violations[0].field, "highest_bid");
+
+        // Sin máscara tampoco se puede sobrescribir highest_bid
+        let legacy = UpdateAuctionRequest { id: auction_id.clone(), highest_bid: "999".to_string(), ..Default::default() };
+        let err = service.update_auction(as_admin(legacy)).await.unwrap_err();
+        assert_eq!(crate::error::error_info(&err).unwrap().reason, "IMMUTABLE_FIELD");
+
+        let unknown = UpdateAuctionRequest { id: auction_id, update_mask: mask(&["precio"]), ..Default::default() };
+        let err = service.update_auction(as_admin(unknown)).await.unwrap_err();
+        assert_eq!(crate::error::error_info(&err).unwrap().reason, "UNKNOWN_FIELD");
+    }
+
+    #[tokio::test]
+    async fn test_prices_and_times_only_editable_while_pending() {
+        let service = setup_service().await;
+        let pending_id = create_pending_auction(&service).await;
+        let reprice = |id: &str| UpdateAuctionRequest {
+            id: id.to_string(),
+            base_price: "200.00".to_string(),
+            update_mask: mask(&["base_price"]),
+            ..Default::default()
+        };
+        let auction = service.update_auction(as_admin(reprice(&pending_id))).await.unwrap().into_inner().auction.unwrap();
+        assert_eq!(auction.base_price.parse::<f64>().unwrap(), 200.0);
+
+        let active_id = create_active_auction(&service).await;
+        let err = service.update_auction(as_admin(reprice(&active_id))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+        let info = crate::error::error_info(&err).unwrap();
+        assert_eq!(info.reason, "FIELD_NOT_EDITABLE");
+        assert_eq!(info.metadata["field"], "base_price");
+        assert_eq!(info.metadata["status"], "active");
+
+        let retime = UpdateAuctionRequest {
+            id: active_id.clone(),
+            end_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 7200, nanos: 0 }),
+            ..Default::default()
+        };
+        let err = service.update_auction(as_admin(retime)).await.unwrap_err();
+        assert_eq!(crate::error::error_info(&err).unwrap().reason, "FIELD_NOT_EDITABLE");
+
+        // Los datos descriptivos siguen siendo editables
+        let rename = UpdateAuctionRequest { id: active_id, title: "Nuevo".to_string(), update_mask: mask(&["title"]), ..Default::default() };
+        service.update_auction(as_admin(rename)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_update_validates_against_stored_values() {
+        let service = setup_service().await;
+        let auction_id = create_pending_auction(&service).await;
+
+        // La subasta guardada empieza en 100 segundos: un fin anterior es inválido
+        let end_before_start = UpdateAuctionRequest {
+            id: auction_id.clone(),
+            end_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 50, nanos: 0 }),
+            ..Default::default()
+        };
+        let err = service.update_auction(as_admin(end_before_start)).await.unwrap_err();
+        assert_eq!(crate::error::error_info(&err).unwrap().reason, "INVALID_DATE_RANGE");
+
+        let zero_price = UpdateAuctionRequest {
+            id: auction_id.clone(),
+            base_price: "0".to_string(),
+            update_mask: mask(&["base_price"]),
+            ..Default::default()
+        };
+        let err = service.update_auction(as_admin(zero_price)).await.unwrap_err();
+        assert_eq!(crate::error::error_info(&err).unwrap().reason, "NOT_POSITIVE");
+
+        // Pasar a CLP con un incremento de 10.00 es válido; 10.50 no
+        let to_clp = UpdateAuctionRequest {
+            id: auction_id.clone(),
+            currency: "CLP".to_string(),
+            min_bid_increment: "10.50".to_string(),
+            ..Default::default()
+        };
+        let err = service.update_auction(as_admin(to_clp)).await.unwrap_err();
+        assert_eq!(crate::error::error_info(&err).unwrap().reason, "INVALID_SCALE");
+        let to_clp = UpdateAuctionRequest { id: auction_id, currency: "CLP".to_string(), ..Default::default() };
+        service.update_auction(as_admin(to_clp)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_update_auction_rejects_stale_expected_version() {
+        let service = setup_service().await;
+        let auction_id = create_pending_auction(&service).await;
+        let update = |title: &str, expected_version: i64| UpdateAuctionRequest {
+            id: auction_id.clone(),
+            title: title.to_string(),
+            expected_version,
+            ..Default::default()
+        };
+
+        let first = service.update_auction(as_admin(update("Primero", 1))).await.unwrap().into_inner();
+        assert_eq!(first.auction.unwrap().version, 2);
+
+        // Un segundo administrador todavía tiene la versión 1
+        let err = service.update_auction(as_admin(update("Segundo", 1))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Aborted);
+        let info = crate::error::error_info(&err).unwrap();
+        assert_eq!(info.reason, "VERSION_CONFLICT");
+        assert_eq!(info.metadata["current_version"], "2");
+
+        let auction = service.get_auction(Request::new(GetAuctionRequest { id: auction_id.clone(), ..Default::default() }))
+            .await.unwrap().into_inner().auction.unwrap();
+        assert_eq!(auction.title, "Primero");
+
+        // Sin expected_version la escritura no se comprueba
+        let third = service.update_auction(as_admin(update("Tercero", 0))).await.unwrap().into_inner();
+        assert_eq!(third.auction.unwrap().version, 3);
+    }
+
+    #[tokio::test]
+    async fn test_versioned_write_detects_concurrent_change() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        let id = Uuid::parse_str(&auction_id).unwrap();
+        let stale = AuctionEntity::find_by_id(id).one(&service.db).await.unwrap().unwrap();
+
+        // La puja también incrementa la versión
+        place_bid(&service, &auction_id, "alice", "120.00").await;
+        let current = AuctionEntity::find_by_id(id).one(&service.db).await.unwrap().unwrap();
+        assert_eq!(current.version, stale.version + 1);
+
+        // Escribir highest_bid desde la copia anterior no pisa la puja
+        let mut active: AuctionActiveModel = stale.clone().into();
+        active.highest_bid = Set(Some(rust_decimal::Decimal::from(110)));
+        let err = update_auction_versioned(&service.db, active).await.unwrap_err();
+        assert!(matches!(err, AppError::VersionConflict { expected, current: c } if expected == stale.version && c == current.version));
+        let after = AuctionEntity::find_by_id(id).one(&service.db).await.unwrap().unwrap();
+        assert_eq!(after.highest_bid, current.highest_bid);
+    }
+
+    #[tokio::test]
+    async fn test_create_auction_retry_with_idempotency_key_returns_same_auction() {
         let service = setup_service().await;
-        
-        // Crear una subasta
         let auction_req = CreateAuctionRequest {
-            user_id: uuid::Uuid::new_v4().to_string(),
-            item_id: uuid::Uuid::new_v4().to_string(),
+            user_id: "seller".to_string(),
+            item_id: "item".to_string(),
             title: "Test Auction".to_string(),
-            description: "desc".to_string(),
             category: "Electronics".to_string(),
             start_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 100, nanos: 0 }),
             end_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 3600, nanos: 0 }),
             base_price: "100.00".to_string(),
             min_bid_increment: "10.00".to_string(),
-            highest_bid: "".to_string(),
-            currency: "USD".to_string(),
+            idempotency_key: "alta-1".to_string(),
+            ..Default::default()
         };
-        
-        let auction_response = service.create_auction(Request::new(auction_req)).await.unwrap().into_inner();
-        let auction_id = auction_response.auction.unwrap().id;
+        let first = service.create_auction(signed(auction_req.clone())).await.unwrap().into_inner();
+        let second = service.create_auction(signed(auction_req.clone())).await.unwrap().into_inner();
+        assert_eq!(second.auction.unwrap().id, first.auction.unwrap().id);
+        assert_eq!(AuctionEntity::find().count(&service.db).await.unwrap(), 1);
+
+        // Otro vendedor puede usar la misma clave sin colisionar
+        let other = CreateAuctionRequest { user_id: "other".to_string(), ..auction_req };
+        service.create_auction(signed(other)).await.unwrap();
+        assert_eq!(AuctionEntity::find().count(&service.db).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_auction_with_bids() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
 
         // Crear algunas pujas
         for i in 1..=3 {
@@ -832,13 +2136,16 @@ CREATE TABLE bid (
                 auction_id: auction_id.clone(),
                 user_id: uuid::Uuid::new_v4().to_string(),
                 amount: format!("{}.00", 100 + i * 10),
+                max_amount: "".to_string(),
+                idempotency_key: "".to_string(),
             };
-            service.create_bid(Request::new(bid_req)).await.unwrap();
+            service.create_bid(signed(bid_req)).await.unwrap();
         }
 
         // Obtener la subasta con pujas
         let get_req = GetAuctionRequest {
             id: auction_id.clone(),
+            include_deleted: false,
         };
         let response = service.get_auction(Request::new(get_req)).await.unwrap().into_inner();
         let auction = response.auction.unwrap();
@@ -848,9 +2155,700 @@ CREATE TABLE bid (
         assert_eq!(auction.bids[0].amount, "130.00"); // La puja más reciente primero
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_create_bid_concurrent_keeps_true_max() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+
+        // Lanzar muchas pujas en paralelo con montos distintos
+        let amounts: Vec<i64> = (1..=30).map(|i| 100 + i * 10).collect();
+        let mut handles = Vec::new();
+        for &amount in amounts.iter().rev().chain(amounts.iter()) {
+            let service = service.clone();
+            let auction_id = auction_id.clone();
+            handles.push(tokio::spawn(async move {
+                let bid_req = CreateBidRequest {
+                    auction_id,
+                    user_id: uuid::Uuid::new_v4().to_string(),
+                    amount: format!("{}.00", amount),
+                    max_amount: "".to_string(),
+                    idempotency_key: "".to_string(),
+                };
+                service.create_bid(signed(bid_req)).await
+            }));
+        }
+        for handle in handles {
+            // Las pujas rechazadas solo pueden fallar por ser demasiado bajas
+            if let Err(status) = handle.await.unwrap() {
+                assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+            }
+        }
+
+        let true_max = rust_decimal::Decimal::from(*amounts.iter().max().unwrap());
+        let auction = service.get_auction(Request::new(GetAuctionRequest { id: auction_id, ..Default::default() }))
+            .await.unwrap().into_inner().auction.unwrap();
+        assert_eq!(auction.highest_bid.parse::<rust_decimal::Decimal>().unwrap(), true_max);
+
+        // Cada puja aceptada debe superar a la anterior por al menos el incremento mínimo
+        let mut accepted: Vec<rust_decimal::Decimal> = auction.bids.iter()
+            .map(|b| b.amount.parse().unwrap())
+            .collect();
+        accepted.sort();
+        assert_eq!(accepted.last().copied(), Some(true_max));
+        for pair in accepted.windows(2) {
+            assert!(pair[1] - pair[0] >= rust_decimal::Decimal::from(10));
+        }
+    }
+
+    async fn place_bid(service: &MyAuctionService, auction_id: &str, user_id: &str, amount: &str) {
+        let bid_req = CreateBidRequest {
+            auction_id: auction_id.to_string(),
+            user_id: user_id.to_string(),
+            amount: amount.to_string(),
+            max_amount: "".to_string(),
+            idempotency_key: "".to_string(),
+        };
+        service.create_bid(signed(bid_req)).await.unwrap();
+    }
+
+    async fn bid_statuses(service: &MyAuctionService, auction_id: &str) -> Vec<(String, String)> {
+        let req = ListBidsRequest { auction_id: auction_id.to_string() };
+        let mut bids: Vec<(String, String)> = service.list_bids(Request::new(req)).await.unwrap().into_inner()
+            .bids.into_iter().map(|b| (b.user_id, b.status)).collect();
+        bids.sort();
+        bids
+    }
+
+    fn cancel_request(id: &str, force: bool) -> CancelAuctionRequest {
+        CancelAuctionRequest {
+            id: id.to_string(),
+            actor_id: "admin".to_string(),
+            reason: "Artículo retirado por el vendedor".to_string(),
+            force,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancel_auction_without_bids() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+
+        let response = service.cancel_auction(as_admin(cancel_request(&auction_id, false))).await.unwrap().into_inner();
+        assert_eq!(response.auction.unwrap().status, "cancelled");
+        assert_eq!(response.cancelled_bids, 0);
+        let closure = response.closure.unwrap();
+        assert_eq!(closure.action, "cancelled");
+        assert_eq!(closure.actor_id, "admin");
+        assert_eq!(closure.reason, "Artículo retirado por el vendedor");
+
+        // Una subasta cancelada no puede volver a cancelarse ni cerrarse
+        let err = service.cancel_auction(as_admin(cancel_request(&auction_id, true))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+        let close_req = CloseAuctionRequest { id: auction_id, actor_id: "admin".to_string(), reason: "fin".to_string() };
+        let err = service.close_auction(as_admin(close_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_auction_with_bids_requires_force() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        place_bid(&service, &auction_id, "alice", "120").await;
+        place_bid(&service, &auction_id, "bob", "130").await;
+
+        let err = service.cancel_auction(as_admin(cancel_request(&auction_id, false))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+        let get_req = GetAuctionRequest { id: auction_id.clone(), ..Default::default() };
+        let auction = service.get_auction(Request::new(get_req)).await.unwrap().into_inner().auction.unwrap();
+        assert_eq!(auction.status, "active");
+
+        let response = service.cancel_auction(as_admin(cancel_request(&auction_id, true))).await.unwrap().into_inner();
+        assert_eq!(response.cancelled_bids, 2);
+        assert_eq!(bid_statuses(&service, &auction_id).await, vec![
+            ("alice".to_string(), "cancelled".to_string()),
+            ("bob".to_string(), "cancelled".to_string()),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_auction_cancels_active_proxy_bids() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        service.create_bid(signed(proxy_bid_request(&auction_id, "alice", "200.00"))).await.unwrap();
+
+        let response = service.cancel_auction(as_admin(cancel_request(&auction_id, true))).await.unwrap().into_inner();
+        assert_eq!(response.cancelled_bids, 1);
+        let proxies = crate::models::proxy_bid::Entity::find().all(&service.db).await.unwrap();
+        assert_eq!(proxies.len(), 1);
+        assert_eq!(proxies[0].status, ProxyBidStatus::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_close_auction_settles_bids() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        place_bid(&service, &auction_id, "alice", "120").await;
+        place_bid(&service, &auction_id, "bob", "130").await;
+
+        let close_req = CloseAuctionRequest {
+            id: auction_id.clone(),
+            actor_id: "admin".to_string(),
+            reason: "Cierre anticipado acordado".to_string(),
+        };
+        let response = service.close_auction(as_admin(close_req)).await.unwrap().into_inner();
+        assert_eq!(response.auction.unwrap().status, "completed");
+        assert_eq!(response.closure.unwrap().action, "completed");
+        let result = response.result.unwrap();
+        assert!(result.has_winner);
+        assert_eq!(result.winner_user_id, "bob");
+        assert_eq!(bid_statuses(&service, &auction_id).await, vec![
+            ("alice".to_string(), "lost".to_string()),
+            ("bob".to_string(), "won".to_string()),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_close_and_cancel_validate_request() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+
+        let mut req = cancel_request(&auction_id, false);
+        req.reason = "  ".to_string();
+        let err = service.cancel_auction(as_admin(req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+        let close_req = CloseAuctionRequest { id: auction_id, actor_id: "".to_string(), reason: "fin".to_string() };
+        let err = service.close_auction(as_admin(close_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+        let err = service.cancel_auction(as_admin(cancel_request(&uuid::Uuid::new_v4().to_string(), false))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+    }
+
+    async fn bid_id_of(service: &MyAuctionService, auction_id: &str, user_id: &str) -> String {
+        let req = ListBidsRequest { auction_id: auction_id.to_string() };
+        service.list_bids(Request::new(req)).await.unwrap().into_inner()
+            .bids.into_iter().find(|b| b.user_id == user_id).unwrap().id
+    }
+
+    fn retract_request(bid_id: &str, user_id: &str) -> RetractBidRequest {
+        RetractBidRequest {
+            bid_id: bid_id.to_string(),
+            user_id: user_id.to_string(),
+            reason: "Error de tipeo".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retract_leading_bid_recomputes_highest_bid() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        place_bid(&service, &auction_id, "alice", "120").await;
+        place_bid(&service, &auction_id, "bob", "130").await;
+        place_bid(&service, &auction_id, "carol", "10000").await;
+
+        let carol_bid = bid_id_of(&service, &auction_id, "carol").await;
+        let response = service.retract_bid(signed(retract_request(&carol_bid, "carol"))).await.unwrap().into_inner();
+        let retracted = response.bid.unwrap();
+        assert_eq!(retracted.status, "retracted");
+        assert_eq!(retracted.retraction_reason, "Error de tipeo");
+        assert!(retracted.retracted_at.is_some());
+        assert_eq!(response.highest_bid.parse::<f64>().unwrap(), 130.0);
+        assert_eq!(response.leading_bid.unwrap().user_id, "bob");
+
+        assert_eq!(bid_statuses(&service, &auction_id).await, vec![
+            ("alice".to_string(), "outbid".to_string()),
+            ("bob".to_string(), "active".to_string()),
+            ("carol".to_string(), "retracted".to_string()),
+        ]);
+        let get_req = GetAuctionRequest { id: auction_id.clone(), ..Default::default() };
+        let auction = service.get_auction(Request::new(get_req)).await.unwrap().into_inner().auction.unwrap();
+        assert_eq!(auction.highest_bid.parse::<f64>().unwrap(), 130.0);
+        let highest_req = GetHighestBidRequest { auction_id: auction_id.clone() };
+        let highest = service.get_highest_bid(Request::new(highest_req)).await.unwrap().into_inner().bid.unwrap();
+        assert_eq!(highest.user_id, "bob");
+
+        // Las nuevas pujas se validan contra el precio recalculado
+        place_bid(&service, &auction_id, "dave", "140").await;
+    }
+
+    #[tokio::test]
+    async fn test_retract_outbid_and_last_bid() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        place_bid(&service, &auction_id, "alice", "120").await;
+        place_bid(&service, &auction_id, "bob", "130").await;
+
+        // Retractar una puja superada no cambia la líder
+        let alice_bid = bid_id_of(&service, &auction_id, "alice").await;
+        let response = service.retract_bid(signed(retract_request(&alice_bid, "alice"))).await.unwrap().into_inner();
+        assert_eq!(response.highest_bid.parse::<f64>().unwrap(), 130.0);
+
+        // Sin pujas vigentes la subasta queda sin highest_bid
+        let bob_bid = bid_id_of(&service, &auction_id, "bob").await;
+        let response = service.retract_bid(signed(retract_request(&bob_bid, "bob"))).await.unwrap().into_inner();
+        assert_eq!(response.highest_bid, "");
+        assert!(response.leading_bid.is_none());
+
+        // Una puja ya retractada no se puede retractar de nuevo
+        let err = service.retract_bid(signed(retract_request(&bob_bid, "bob"))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+    }
+
+    #[tokio::test]
+    async fn test_retract_bid_retracts_active_proxy_bid() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        service.create_bid(signed(proxy_bid_request(&auction_id, "alice", "200.00"))).await.unwrap();
+
+        let alice_bid = bid_id_of(&service, &auction_id, "alice").await;
+        let response = service.retract_bid(signed(retract_request(&alice_bid, "alice"))).await.unwrap().into_inner();
+        assert_eq!(response.bid.unwrap().status, "retracted");
+        let proxies = crate::models::proxy_bid::Entity::find().all(&service.db).await.unwrap();
+        assert_eq!(proxies.len(), 1);
+        assert_eq!(proxies[0].status, ProxyBidStatus::Retracted);
+    }
+
+    #[tokio::test]
+    async fn test_retract_bid_checks_owner_and_policy() {
+        let service = setup_service().await.with_retraction_policy(RetractionPolicy {
+            window: std::time::Duration::ZERO,
+            cutoff: std::time::Duration::from_secs(24 * 3600),
+        });
+        let auction_id = create_active_auction(&service).await;
+        place_bid(&service, &auction_id, "alice", "120").await;
+        let alice_bid = bid_id_of(&service, &auction_id, "alice").await;
+
+        let err = service.retract_bid(signed(retract_request(&alice_bid, "mallory"))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+
+        // La subasta cierra en una hora: sin ventana y con corte de 24 horas ya no se puede retractar
+        let err = service.retract_bid(signed(retract_request(&alice_bid, "alice"))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+
+        let mut req = retract_request(&alice_bid, "alice");
+        req.reason = "".to_string();
+        let err = service.retract_bid(signed(req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_and_restore_auction() {
+        let service = setup_service().await;
+        let auction_id = create_pending_auction(&service).await;
+
+        let delete_req = DeleteAuctionRequest { id: auction_id.clone() };
+        let deleted = service.delete_auction(as_admin(delete_req)).await.unwrap().into_inner().auction.unwrap();
+        assert_eq!(deleted.id, auction_id);
+        assert_eq!(deleted.title, "Test Auction");
+        assert_eq!(deleted.status, "pending");
+        assert!(deleted.deleted_at.is_some());
+
+        // Oculta por defecto en GetAuction y ListAuctions
+        let get_req = GetAuctionRequest { id: auction_id.clone(), ..Default::default() };
+        let err = service.get_auction(Request::new(get_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+        let listed = service.list_auctions(Request::new(ListAuctionsRequest::default())).await.unwrap().into_inner();
+        assert!(listed.auctions.is_empty());
+
+        // Visible para un administrador con include_deleted
+        let get_req = GetAuctionRequest { id: auction_id.clone(), include_deleted: true };
+        let auction = service.get_auction(as_admin(get_req)).await.unwrap().into_inner().auction.unwrap();
+        assert!(auction.deleted_at.is_some());
+        let list_req = ListAuctionsRequest { include_deleted: true, ..Default::default() };
+        let listed = service.list_auctions(as_admin(list_req)).await.unwrap().into_inner();
+        assert_eq!(listed.auctions.len(), 1);
+
+        // Una subasta eliminada no se puede modificar ni eliminar de nuevo
+        let update_req = UpdateAuctionRequest { id: auction_id.clone(), title: "Nuevo".to_string(), ..Default::default() };
+        let err = service.update_auction(as_admin(update_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+        let delete_req = DeleteAuctionRequest { id: auction_id.clone() };
+        let err = service.delete_auction(as_admin(delete_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+
+        let restore_req = RestoreAuctionRequest { id: auction_id.clone() };
+        let restored = service.restore_auction(as_admin(restore_req)).await.unwrap().into_inner().auction.unwrap();
+        assert!(restored.deleted_at.is_none());
+        let get_req = GetAuctionRequest { id: auction_id.clone(), ..Default::default() };
+        assert!(service.get_auction(Request::new(get_req)).await.is_ok());
+
+        let restore_req = RestoreAuctionRequest { id: auction_id };
+        let err = service.restore_auction(as_admin(restore_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+    }
+
+    #[tokio::test]
+    async fn test_include_deleted_requires_admin() {
+        let service = setup_service().await;
+        let auction_id = create_pending_auction(&service).await;
+        let delete_req = DeleteAuctionRequest { id: auction_id.clone() };
+        service.delete_auction(as_admin(delete_req)).await.unwrap();
+
+        // Un usuario sin rol de administrador no puede ver la subasta eliminada
+        let get_req = GetAuctionRequest { id: auction_id.clone(), include_deleted: true };
+        let err = service.get_auction(as_user("mallory", get_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+        assert_eq!(crate::error::error_info(&err).unwrap().reason, "ADMIN_REQUIRED");
+        let list_req = ListAuctionsRequest { include_deleted: true, ..Default::default() };
+        let err = service.list_auctions(as_user("mallory", list_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+
+        // Sin token tampoco
+        let get_req = GetAuctionRequest { id: auction_id, include_deleted: true };
+        let err = service.get_auction(Request::new(get_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn test_delete_unknown_auction_not_found() {
+        let service = setup_service().await;
+        let delete_req = DeleteAuctionRequest { id: uuid::Uuid::new_v4().to_string() };
+        let err = service.delete_auction(as_admin(delete_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+
+        let delete_req = DeleteAuctionRequest { id: "no-es-uuid".to_string() };
+        let err = service.delete_auction(as_admin(delete_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_delete_refuses_active_or_with_bids() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+
+        let delete_req = DeleteAuctionRequest { id: auction_id.clone() };
+        let err = service.delete_auction(as_admin(delete_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+
+        // Aun cancelada, una subasta con pujas se conserva
+        place_bid(&service, &auction_id, "alice", "120").await;
+        service.cancel_auction(as_admin(cancel_request(&auction_id, true))).await.unwrap();
+        let delete_req = DeleteAuctionRequest { id: auction_id.clone() };
+        let err = service.delete_auction(as_admin(delete_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+        assert_eq!(bid_statuses(&service, &auction_id).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_auction_result_after_completion() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+
+        // Sin cerrar todavía no hay resultado
+        let result_req = GetAuctionResultRequest { auction_id: auction_id.clone() };
+        let err = service.get_auction_result(Request::new(result_req.clone())).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+
+        let mut winner_bid_id = String::new();
+        for (i, user) in ["alice", "bob"].iter().enumerate() {
+            let bid_req = CreateBidRequest {
+                auction_id: auction_id.clone(),
+                user_id: user.to_string(),
+                amount: format!("{}.00", 110 + i * 10),
+                max_amount: "".to_string(),
+                idempotency_key: "".to_string(),
+            };
+            winner_bid_id = service.create_bid(signed(bid_req)).await.unwrap()
+                .into_inner().bid.unwrap().id;
+        }
+
+        let complete_req = UpdateAuctionRequest {
+            id: auction_id.clone(),
+            status: "completed".to_string(),
+            ..Default::default()
+        };
+        service.update_auction(as_admin(complete_req)).await.unwrap();
+
+        let result = service.get_auction_result(Request::new(result_req)).await.unwrap()
+            .into_inner().result.unwrap();
+        assert!(result.has_winner);
+        assert_eq!(result.winning_bid_id, winner_bid_id);
+        assert_eq!(result.winner_user_id, "bob");
+        assert_eq!(result.final_price.parse::<rust_decimal::Decimal>().unwrap(), rust_decimal::Decimal::from(120));
+        assert_eq!(result.currency, "USD");
+
+        let bids = service.list_bids(Request::new(ListBidsRequest { auction_id })).await.unwrap()
+            .into_inner().bids;
+        for bid in bids {
+            let expected = if bid.id == winner_bid_id { "won" } else { "lost" };
+            assert_eq!(bid.status, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_auction_result_with_unknown_stored_currency_is_an_error() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        let complete_req = UpdateAuctionRequest { id: auction_id.clone(), status: "completed".to_string(), ..Default::default() };
+        service.update_auction(as_admin(complete_req)).await.unwrap();
+
+        // Una moneda guardada que no existe no se informa como otra moneda
+        service.db.execute_unprepared("UPDATE auction_result SET currency = 'XYZ'").await.unwrap();
+        let result_req = GetAuctionResultRequest { auction_id };
+        let err = service.get_auction_result(Request::new(result_req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Internal);
+    }
+
+    #[tokio::test]
+    async fn test_reserve_price_hidden_and_not_met() {
+        let service = setup_service().await;
+        let auction_req = CreateAuctionRequest {
+            user_id: uuid::Uuid::new_v4().to_string(),
+            item_id: uuid::Uuid::new_v4().to_string(),
+            title: "Reserve Auction".to_string(),
+            description: "desc".to_string(),
+            category: "Electronics".to_string(),
+            start_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 100, nanos: 0 }),
+            end_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 3600, nanos: 0 }),
+            base_price: "100.00".to_string(),
+            min_bid_increment: "10.00".to_string(),
+            highest_bid: "".to_string(),
+            reserve_price: "500.00".to_string(),
+            extension_window_seconds: 0,
+            extension_seconds: 0,
+            max_extensions: 0,
+            currency: "USD".to_string(),
+            idempotency_key: "".to_string(),
+            currency_value: 0,
+        };
+        let auction = service.create_auction(signed(auction_req)).await.unwrap()
+            .into_inner().auction.unwrap();
+        assert!(auction.has_reserve);
+        assert!(!auction.reserve_met);
+
+        let activate_req = UpdateAuctionRequest {
+            id: auction.id.clone(),
+            status: "active".to_string(),
+            ..Default::default()
+        };
+        service.update_auction(as_admin(activate_req)).await.unwrap();
+
+        // Las pujas bajo la reserva se aceptan igualmente
+        let bid_req = CreateBidRequest {
+            auction_id: auction.id.clone(),
+            user_id: "bidder".to_string(),
+            amount: "200.00".to_string(),
+            max_amount: "".to_string(),
+            idempotency_key: "".to_string(),
+        };
+        service.create_bid(signed(bid_req)).await.unwrap();
+
+        let complete_req = UpdateAuctionRequest {
+            id: auction.id.clone(),
+            status: "completed".to_string(),
+            ..Default::default()
+        };
+        service.update_auction(as_admin(complete_req)).await.unwrap();
+
+        let result = service.get_auction_result(Request::new(GetAuctionResultRequest { auction_id: auction.id }))
+            .await.unwrap().into_inner().result.unwrap();
+        assert!(!result.reserve_met);
+        assert!(!result.has_winner);
+        assert!(result.winner_user_id.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_auction_reserve_below_base_price() {
+        let service = setup_service().await;
+        let req = CreateAuctionRequest {
+            user_id: uuid::Uuid::new_v4().to_string(),
+            item_id: uuid::Uuid::new_v4().to_string(),
+            title: "Test Auction".to_string(),
+            description: "desc".to_string(),
+            category: "Electronics".to_string(),
+            start_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 100, nanos: 0 }),
+            end_time: Some(prost_types::Timestamp { seconds: chrono::Utc::now().timestamp() + 3600, nanos: 0 }),
+            base_price: "100.00".to_string(),
+            min_bid_increment: "10.00".to_string(),
+            highest_bid: "".to_string(),
+            reserve_price: "50.00".to_string(),
+            extension_window_seconds: 0,
+            extension_seconds: 0,
+            max_extensions: 0,
+            currency: "USD".to_string(),
+            idempotency_key: "".to_string(),
+            currency_value: 0,
+        };
+        let err = service.create_auction(signed(req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
+    fn proxy_bid_request(auction_id: &str, user_id: &str, max_amount: &str) -> CreateBidRequest {
+        CreateBidRequest {
+            auction_id: auction_id.to_string(),
+            user_id: user_id.to_string(),
+            amount: "".to_string(),
+            max_amount: max_amount.to_string(),
+            idempotency_key: "".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_competing_proxy_bids() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        let dec = |v: &str| v.parse::<rust_decimal::Decimal>().unwrap();
+
+        // La primera puja automática abre al precio base
+        let res = service.create_bid(signed(proxy_bid_request(&auction_id, "alice", "200.00")))
+            .await.unwrap().into_inner();
+        assert!(res.is_leading);
+        assert_eq!(dec(&res.highest_bid), dec("100"));
+
+        // bob agota su máximo y alice responde con un incremento
+        let res = service.create_bid(signed(proxy_bid_request(&auction_id, "bob", "150.00")))
+            .await.unwrap().into_inner();
+        assert!(!res.is_leading);
+        assert_eq!(dec(&res.bid.unwrap().amount), dec("150"));
+        assert_eq!(dec(&res.highest_bid), dec("160"));
+
+        // Una puja manual por debajo del máximo de alice es respondida automáticamente
+        let manual = CreateBidRequest {
+            auction_id: auction_id.clone(),
+            user_id: "carol".to_string(),
+            amount: "170.00".to_string(),
+            max_amount: "".to_string(),
+            idempotency_key: "".to_string(),
+        };
+        let res = service.create_bid(signed(manual)).await.unwrap().into_inner();
+        assert!(!res.is_leading);
+        assert_eq!(dec(&res.highest_bid), dec("180"));
+
+        // dave supera el máximo de alice y paga su máximo más el incremento
+        let res = service.create_bid(signed(proxy_bid_request(&auction_id, "dave", "300.00")))
+            .await.unwrap().into_inner();
+        assert!(res.is_leading);
+        assert_eq!(dec(&res.highest_bid), dec("210"));
+
+        let auction = service.get_auction(Request::new(GetAuctionRequest { id: auction_id.clone(), ..Default::default() }))
+            .await.unwrap().into_inner().auction.unwrap();
+        assert_eq!(dec(&auction.highest_bid), dec("210"));
+        let active: Vec<_> = auction.bids.iter().filter(|b| b.status == "active").collect();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].user_id, "dave");
+
+        let complete_req = UpdateAuctionRequest {
+            id: auction_id.clone(),
+            status: "completed".to_string(),
+            ..Default::default()
+        };
+        service.update_auction(as_admin(complete_req)).await.unwrap();
+        let result = service.get_auction_result(Request::new(GetAuctionResultRequest { auction_id }))
+            .await.unwrap().into_inner().result.unwrap();
+        assert_eq!(result.winner_user_id, "dave");
+        assert_eq!(dec(&result.final_price), dec("210"));
+    }
+
+    #[tokio::test]
+    async fn test_equal_proxy_bids_earliest_wins() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+
+        service.create_bid(signed(proxy_bid_request(&auction_id, "early", "150.00"))).await.unwrap();
+        let res = service.create_bid(signed(proxy_bid_request(&auction_id, "late", "150.00")))
+            .await.unwrap().into_inner();
+        assert!(!res.is_leading);
+        assert!(res.bid.is_none());
+        assert_eq!(res.highest_bid.parse::<rust_decimal::Decimal>().unwrap(), rust_decimal::Decimal::from(150));
+
+        let highest = service.get_auction(Request::new(GetAuctionRequest { id: auction_id, ..Default::default() }))
+            .await.unwrap().into_inner().auction.unwrap()
+            .bids.into_iter().find(|b| b.status == "active").unwrap();
+        assert_eq!(highest.user_id, "early");
+    }
+
+    #[tokio::test]
+    async fn test_create_bid_rejects_amount_and_max_amount() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+        let mut req = proxy_bid_request(&auction_id, "alice", "200.00");
+        req.amount = "150.00".to_string();
+        let err = service.create_bid(signed(req)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_bid_in_closing_window_extends_end_time() {
+        let service = setup_service().await;
+        let auction_id = create_active_auction(&service).await;
+
+        // Ventana de una hora: cualquier puja cae dentro; solo se permite una extensión
+        let soft_close_req = UpdateAuctionRequest {
+            id: auction_id.clone(),
+            extension_window_seconds: 3600,
+            extension_seconds: 600,
+            max_extensions: 1,
+            ..Default::default()
+        };
+        let original_end = service.update_auction(as_admin(soft_close_req)).await.unwrap()
+            .into_inner().auction.unwrap().end_time.unwrap();
+
+        let bid = |user: &str, amount: &str| CreateBidRequest {
+            auction_id: auction_id.clone(),
+            user_id: user.to_string(),
+            amount: amount.to_string(),
+            max_amount: "".to_string(),
+            idempotency_key: "".to_string(),
+        };
+
+        let res = service.create_bid(signed(bid("alice", "110.00"))).await.unwrap().into_inner();
+        assert!(res.end_time_extended);
+        assert_eq!(res.end_time.unwrap().seconds, original_end.seconds + 600);
+
+        // Alcanzado el máximo de extensiones el cierre ya no se mueve
+        let res = service.create_bid(signed(bid("bob", "120.00"))).await.unwrap().into_inner();
+        assert!(!res.end_time_extended);
+        assert_eq!(res.end_time.unwrap().seconds, original_end.seconds + 600);
+
+        let auction = service.get_auction(Request::new(GetAuctionRequest { id: auction_id, ..Default::default() }))
+            .await.unwrap().into_inner().auction.unwrap();
+        assert_eq!(auction.extensions_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_should_extend_end_time_rules() {
+        let now = chrono::Utc::now();
+        let model = AuctionModel {
+            id: uuid::Uuid::new_v4(),
+            user_id: "seller".to_string(),
+            item_id: "item".to_string(),
+            title: "Test".to_string(),
+            description: None,
+            start_time: now - chrono::Duration::hours(1),
+            end_time: now + chrono::Duration::seconds(30),
+            base_price: rust_decimal::Decimal::from(100),
+            min_bid_increment: rust_decimal::Decimal::from(10),
+            highest_bid: None,
+            status: AuctionStatus::Active,
+            currency: AuctionCurrency::USD,
+            category: "Electronics".to_string(),
+            reserve_price: None,
+            extension_window_secs: 60,
+            extension_secs: 120,
+            max_extensions: 2,
+            extensions_count: 0,
+            deleted_at: None,
+            version: 1,
+        };
+        assert!(should_extend_end_time(&model, now));
+
+        // Fuera de la ventana
+        let early = AuctionModel { end_time: now + chrono::Duration::seconds(61), ..model.clone() };
+        assert!(!should_extend_end_time(&early, now));
+
+        // Tope de extensiones alcanzado
+        let capped = AuctionModel { extensions_count: 2, ..model.clone() };
+        assert!(!should_extend_end_time(&capped, now));
+
+        // Cierre suave desactivado
+        let disabled = AuctionModel { extension_window_secs: 0, extension_secs: 0, max_extensions: 0, ..model };
+        assert!(!should_extend_end_time(&disabled, now));
+    }
+
     #[tokio::test]
     async fn test_model_structure() {
-        use crate::models::auction::{ActiveModel, Entity};
+        use crate::models::auction::ActiveModel;
         use sea_orm::Set;
         
         let db = setup_test_db().await;
@@ -862,32 +2860,23 @@ CREATE TABLE bid (
             title: Set("Test Title".to_string()),
             description: Set(Some("Test Description".to_string())),
             category: Set("Electronics".to_string()),
-            start_time: Set(chrono::Utc::now().naive_utc()),
-            end_time: Set(chrono::Utc::now().naive_utc() + chrono::Duration::hours(1)),
+            start_time: Set(chrono::Utc::now()),
+            end_time: Set(chrono::Utc::now() + chrono::Duration::hours(1)),
             base_price: Set(rust_decimal::Decimal::from(100)),
             min_bid_increment: Set(rust_decimal::Decimal::from(10)),
             highest_bid: Set(Some(rust_decimal::Decimal::ZERO)),
-            status: Set("pending".to_string()),
-            currency: Set("USD".to_string()),
+            status: Set(AuctionStatus::Pending),
+            currency: Set(AuctionCurrency::USD),
+            reserve_price: Set(None),
+            extension_window_secs: Set(0),
+            extension_secs: Set(0),
+            max_extensions: Set(0),
+            extensions_count: Set(0),
+            deleted_at: Set(None),
+            version: Set(1),
         };
         
         let result = auction.insert(&db).await;
         assert!(result.is_ok(), "Failed to insert auction: {:?}", result.err());
     }
 }
-
-// Función helper para validar que un string representa un número válido
-fn validate_numeric_string(value: &str, field_name: &str) -> Result<rust_decimal::Decimal, Status> {
-    if value.is_empty() {
-        return Err(Status::invalid_argument(format!("{} no puede estar vacío", field_name)));
-    }
-    
-    value.parse::<rust_decimal::Decimal>()
-        .map_err(|e| {
-            log::error!("Error al parsear {} '{}': {}", field_name, value, e);
-            Status::invalid_argument(format!("{} debe ser un número válido", field_name))
-        })
-}
-
-
-
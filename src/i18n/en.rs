@@ -0,0 +1,87 @@
+use crate::error::AppError;
+use crate::domain::{AuctionCurrency, AuctionStatus};
+
+// Catálogo en inglés
+pub fn message(err: &AppError) -> String {
+    use AppError::*;
+    match err {
+        Required { field } => format!("{} must not be empty", field),
+        InvalidId { field } => format!("{} is not a valid id", field),
+        InvalidNumber { field } => format!("{} must be a valid number", field),
+        NegativeValue { field } => format!("{} must not be negative", field),
+        NotPositive { field } => format!("{} must be greater than zero", field),
+        TooManyDecimals { field, max_scale } => format!("{} allows at most {} decimal places in this currency", field, max_scale),
+        TooLong { field, max } => format!("{} cannot exceed {} characters", field, max),
+        InvalidTimestamp { field } => format!("{} is not a valid date", field),
+        InvalidStatus { .. } => format!(
+            "Invalid status. Allowed values: {}",
+            AuctionStatus::all_valid_statuses().join(", ")
+        ),
+        InvalidCurrency { .. } => format!(
+            "Invalid currency. Allowed values: {}",
+            AuctionCurrency::all_valid_currencies().join(", ")
+        ),
+        InvalidDateRange => "The start time must be before the end time".to_string(),
+        StartTimeInPast => "The start time must not be in the past".to_string(),
+        EndTimeInPast => "The end time must not be in the past".to_string(),
+        ReserveBelowBasePrice => "reserve_price must not be lower than base_price".to_string(),
+        InvalidSoftClose => "extension_window_seconds, extension_seconds and max_extensions must be positive to enable soft close".to_string(),
+        AmountConflict => "Provide either amount or max_amount, not both".to_string(),
+        InvalidPageToken => "Invalid page_token".to_string(),
+        InvalidSort => "Invalid sort".to_string(),
+        PriceRangeInverted => "min_price must not be greater than max_price".to_string(),
+        InvalidIdempotencyKey => "idempotency_key must have between 1 and 255 printable ASCII characters".to_string(),
+        IdempotencyKeyReused => "idempotency_key was already used with a different request".to_string(),
+        ImmutableField { field } => format!("{} cannot be modified", field),
+        UnknownUpdateField { path } => format!("update_mask contains an unknown field: {}", path),
+        AuctionNotFound => "Auction not found".to_string(),
+        BidNotFound => "Bid not found".to_string(),
+        NoBids => "There are no bids for this auction".to_string(),
+        InvalidStatusTransition { from, to, allowed } => format!(
+            "Status transition not allowed: '{}' -> '{}'. Allowed transitions from '{}': {}",
+            from,
+            to,
+            from,
+            if allowed.is_empty() { "none (final state)".to_string() } else { allowed.join(", ") }
+        ),
+        AuctionNotActive { status } => {
+            format!("The auction must be in 'active' status. Current status: '{}'", status)
+        },
+        AuctionEnded => "The auction has ended".to_string(),
+        AuctionNotStarted => "The auction has not started yet".to_string(),
+        BidTooLow { min_required } => format!("The bid must be at least {}", min_required),
+        ProxyMaxNotExceeded => "You already have an active automatic bid with an equal or higher maximum".to_string(),
+        CancelRequiresForce { bid_count } => {
+            format!("The auction has {} bids; use force to cancel it", bid_count)
+        },
+        DeleteWithBids { bid_count } => {
+            format!("An auction with bids cannot be deleted ({} recorded)", bid_count)
+        },
+        DeleteActiveAuction => "An active auction cannot be deleted; cancel it first".to_string(),
+        AuctionNotDeleted => "The auction is not deleted".to_string(),
+        AuctionAlreadyCancelled => "The auction is already cancelled".to_string(),
+        AuctionAlreadyClosed => "The auction is already closed".to_string(),
+        AuctionNotClosed => "The auction has not been closed yet".to_string(),
+        BidNotRetractable { status } => {
+            format!("Only standing bids can be retracted. Current status: '{}'", status)
+        },
+        RetractionWindowExpired => "The deadline to retract this bid has passed".to_string(),
+        FieldNotEditable { field, status } => format!("{} can only be changed while the auction is pending (current status: {})", field, status),
+        VersionConflict { expected, current } => format!(
+            "The auction was modified by another request (expected version {}, current version {})",
+            expected, current
+        ),
+        RateLimited { .. } => format!(
+            "Too many bids; try again in {} seconds",
+            err.retry_after_secs().unwrap_or_default()
+        ),
+        MissingCredentials => "An access token is required (authorization: Bearer <token>)".to_string(),
+        InvalidToken => "Invalid or expired access token".to_string(),
+        NotBidOwner => "Only the bidder can retract this bid".to_string(),
+        CallerMismatch { field } => format!("{} does not match the authenticated user", field),
+        NotAuctionOwner => "Only the auction owner or an administrator can perform this operation".to_string(),
+        SelfBidding => "Sellers cannot bid on their own auction".to_string(),
+        AdminRequired { field } => format!("{} requires administrator permissions", field),
+        Database(_) => "Internal server error".to_string(),
+    }
+}
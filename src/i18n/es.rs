@@ -0,0 +1,87 @@
+use crate::error::AppError;
+use crate::domain::{AuctionCurrency, AuctionStatus};
+
+// Catálogo en español (idioma por defecto del servicio)
+pub fn message(err: &AppError) -> String {
+    use AppError::*;
+    match err {
+        Required { field } => format!("{} no puede estar vacío", field),
+        InvalidId { field } => format!("{} inválido", field),
+        InvalidNumber { field } => format!("{} debe ser un número válido", field),
+        NegativeValue { field } => format!("{} no puede ser negativo", field),
+        NotPositive { field } => format!("{} debe ser mayor que cero", field),
+        TooManyDecimals { field, max_scale } => format!("{} admite como máximo {} decimales en esta moneda", field, max_scale),
+        TooLong { field, max } => format!("{} no puede superar los {} caracteres", field, max),
+        InvalidTimestamp { field } => format!("{} no es una fecha válida", field),
+        InvalidStatus { .. } => format!(
+            "Status inválido. Valores permitidos: {}",
+            AuctionStatus::all_valid_statuses().join(", ")
+        ),
+        InvalidCurrency { .. } => format!(
+            "Moneda inválida. Valores permitidos: {}",
+            AuctionCurrency::all_valid_currencies().join(", ")
+        ),
+        InvalidDateRange => "La fecha de inicio debe ser anterior a la fecha de fin".to_string(),
+        StartTimeInPast => "La fecha de inicio no puede ser en el pasado".to_string(),
+        EndTimeInPast => "La fecha de fin no puede ser en el pasado".to_string(),
+        ReserveBelowBasePrice => "reserve_price no puede ser menor que base_price".to_string(),
+        InvalidSoftClose => "extension_window_seconds, extension_seconds y max_extensions deben ser positivos para activar el cierre suave".to_string(),
+        AmountConflict => "Debe indicar amount o max_amount, no ambos".to_string(),
+        InvalidPageToken => "page_token inválido".to_string(),
+        InvalidSort => "sort inválido".to_string(),
+        PriceRangeInverted => "min_price no puede ser mayor que max_price".to_string(),
+        InvalidIdempotencyKey => "idempotency_key debe tener entre 1 y 255 caracteres ASCII imprimibles".to_string(),
+        IdempotencyKeyReused => "idempotency_key ya fue usada con una solicitud distinta".to_string(),
+        ImmutableField { field } => format!("{} no se puede modificar", field),
+        UnknownUpdateField { path } => format!("update_mask contiene un campo desconocido: {}", path),
+        AuctionNotFound => "Subasta no encontrada".to_string(),
+        BidNotFound => "Puja no encontrada".to_string(),
+        NoBids => "No hay pujas para esta subasta".to_string(),
+        InvalidStatusTransition { from, to, allowed } => format!(
+            "Transición de estado no permitida: '{}' -> '{}'. Transiciones permitidas desde '{}': {}",
+            from,
+            to,
+            from,
+            if allowed.is_empty() { "ninguna (estado final)".to_string() } else { allowed.join(", ") }
+        ),
+        AuctionNotActive { status } => {
+            format!("La subasta debe estar en estado 'active'. Estado actual: '{}'", status)
+        },
+        AuctionEnded => "La subasta ha terminado".to_string(),
+        AuctionNotStarted => "La subasta aún no ha comenzado".to_string(),
+        BidTooLow { min_required } => format!("La puja debe ser al menos {}", min_required),
+        ProxyMaxNotExceeded => "Ya tiene una puja automática activa con un máximo igual o superior".to_string(),
+        CancelRequiresForce { bid_count } => {
+            format!("La subasta tiene {} pujas; use force para cancelarla", bid_count)
+        },
+        DeleteWithBids { bid_count } => {
+            format!("No se puede eliminar una subasta con pujas ({} registradas)", bid_count)
+        },
+        DeleteActiveAuction => "No se puede eliminar una subasta activa; cancélela primero".to_string(),
+        AuctionNotDeleted => "La subasta no está eliminada".to_string(),
+        AuctionAlreadyCancelled => "La subasta ya está cancelada".to_string(),
+        AuctionAlreadyClosed => "La subasta ya está cerrada".to_string(),
+        AuctionNotClosed => "La subasta aún no ha sido cerrada".to_string(),
+        BidNotRetractable { status } => {
+            format!("Solo se pueden retractar pujas vigentes. Estado actual: '{}'", status)
+        },
+        RetractionWindowExpired => "El plazo para retractar esta puja ya venció".to_string(),
+        FieldNotEditable { field, status } => format!("{} solo se puede cambiar mientras la subasta está pendiente (estado actual: {})", field, status),
+        VersionConflict { expected, current } => format!(
+            "La subasta fue modificada por otra solicitud (versión esperada {}, versión actual {})",
+            expected, current
+        ),
+        RateLimited { .. } => format!(
+            "Demasiadas pujas; intente nuevamente en {} segundos",
+            err.retry_after_secs().unwrap_or_default()
+        ),
+        MissingCredentials => "Se requiere un token de acceso (authorization: Bearer <token>)".to_string(),
+        InvalidToken => "Token de acceso inválido o expirado".to_string(),
+        NotBidOwner => "Solo quien colocó la puja puede retractarla".to_string(),
+        CallerMismatch { field } => format!("{} no coincide con el usuario autenticado", field),
+        NotAuctionOwner => "Solo el dueño de la subasta o un administrador puede realizar esta operación".to_string(),
+        SelfBidding => "El vendedor no puede pujar en su propia subasta".to_string(),
+        AdminRequired { field } => format!("{} requiere permisos de administrador", field),
+        Database(_) => "Error interno del servidor".to_string(),
+    }
+}
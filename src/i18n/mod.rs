@@ -0,0 +1,188 @@
+use std::future::Future;
+use std::task::{Context, Poll};
+
+use tonic::codegen::{http, BoxFuture, Service};
+use tonic::server::NamedService;
+
+use crate::error::AppError;
+
+mod en;
+mod es;
+mod pt;
+
+// Idiomas con catálogo de mensajes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    Es,
+    En,
+    Pt,
+}
+
+impl Locale {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::Es => "es",
+            Locale::En => "en",
+            Locale::Pt => "pt",
+        }
+    }
+
+    // Acepta etiquetas como "pt", "pt-BR" o "EN_us"; solo importa el idioma principal
+    pub fn parse(tag: &str) -> Option<Self> {
+        let primary = tag.trim().split(['-', '_']).next()?.to_lowercase();
+        match primary.as_str() {
+            "es" => Some(Locale::Es),
+            "en" => Some(Locale::En),
+            "pt" => Some(Locale::Pt),
+            _ => None,
+        }
+    }
+
+    // Lee DEFAULT_LOCALE; sin valor o con un idioma sin catálogo se usa español
+    pub fn fallback_from_env() -> Self {
+        std::env::var("DEFAULT_LOCALE")
+            .ok()
+            .and_then(|v| Locale::parse(&v))
+            .unwrap_or_default()
+    }
+
+    // Elige el idioma soportado con mayor peso q de un encabezado accept-language;
+    // en empate gana el que aparece primero
+    pub fn negotiate(accept_language: Option<&str>, fallback: Locale) -> Self {
+        let mut best: Option<(Locale, f32)> = None;
+        for entry in accept_language.unwrap_or_default().split(',') {
+            let mut parts = entry.split(';');
+            let Some(locale) = parts.next().and_then(Locale::parse) else {
+                continue;
+            };
+            let weight = parts
+                .filter_map(|p| p.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if weight > 0.0 && best.is_none_or(|(_, w)| weight > w) {
+                best = Some((locale, weight));
+            }
+        }
+        best.map(|(locale, _)| locale).unwrap_or(fallback)
+    }
+
+    pub(crate) fn message(&self, err: &AppError) -> String {
+        match self {
+            Locale::Es => es::message(err),
+            Locale::En => en::message(err),
+            Locale::Pt => pt::message(err),
+        }
+    }
+}
+
+tokio::task_local! {
+    static LOCALE: Locale;
+}
+
+// Idioma de la solicitud en curso; fuera de una solicitud, español
+pub fn current() -> Locale {
+    LOCALE.try_with(|locale| *locale).unwrap_or_default()
+}
+
+// Ejecuta `f` con `locale` como idioma de los mensajes de error
+pub async fn scope<F: Future>(locale: Locale, f: F) -> F::Output {
+    LOCALE.scope(locale, f).await
+}
+
+// Envuelve un servicio gRPC para que cada solicitud se atienda en el idioma
+// indicado por su metadata accept-language
+#[derive(Debug, Clone)]
+pub struct LocaleService<S> {
+    inner: S,
+    fallback: Locale,
+}
+
+impl<S> LocaleService<S> {
+    pub fn new(inner: S, fallback: Locale) -> Self {
+        LocaleService { inner, fallback }
+    }
+}
+
+impl<S, B> Service<http::Request<B>> for LocaleService<S>
+where
+    S: Service<http::Request<B>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<S::Response, S::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        let header = req.headers().get("accept-language").and_then(|v| v.to_str().ok());
+        let locale = Locale::negotiate(header, self.fallback);
+        // Los interceptores corren dentro de `call`, así que también necesitan el idioma
+        let future = LOCALE.sync_scope(locale, || self.inner.call(req));
+        Box::pin(LOCALE.scope(locale, future))
+    }
+}
+
+impl<S: NamedService> NamedService for LocaleService<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate_accept_language() {
+        assert_eq!(Locale::negotiate(Some("pt-BR"), Locale::Es), Locale::Pt);
+        assert_eq!(Locale::negotiate(Some("fr-FR, en;q=0.8, es;q=0.9"), Locale::Pt), Locale::Es);
+        assert_eq!(Locale::negotiate(Some("en-US,en;q=0.9"), Locale::Es), Locale::En);
+        // Idiomas sin catálogo o con q=0 usan el idioma de respaldo
+        assert_eq!(Locale::negotiate(Some("fr, de"), Locale::En), Locale::En);
+        assert_eq!(Locale::negotiate(Some("pt;q=0"), Locale::En), Locale::En);
+        assert_eq!(Locale::negotiate(None, Locale::Pt), Locale::Pt);
+    }
+
+    #[tokio::test]
+    async fn test_errors_render_in_request_locale() {
+        let status = scope(Locale::En, async { tonic::Status::from(AppError::AuctionNotFound) }).await;
+        assert_eq!(status.message(), "Auction not found");
+        let status = scope(Locale::Pt, async { tonic::Status::from(AppError::AuctionNotFound) }).await;
+        assert_eq!(status.message(), "Leilão não encontrado");
+        // Fuera de una solicitud se usa español
+        assert_eq!(tonic::Status::from(AppError::AuctionNotFound).message(), "Subasta no encontrada");
+
+        // El código de motivo no depende del idioma
+        let info = crate::error::error_info(&status).unwrap();
+        assert_eq!(info.reason, "AUCTION_NOT_FOUND");
+    }
+
+    // Servicio de prueba que responde con el idioma activo al atender la solicitud
+    #[derive(Clone)]
+    struct EchoLocale;
+
+    impl Service<http::Request<()>> for EchoLocale {
+        type Response = Locale;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Locale, Self::Error>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: http::Request<()>) -> Self::Future {
+            Box::pin(async { Ok(current()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_locale_service_scopes_each_request() {
+        let mut service = LocaleService::new(EchoLocale, Locale::En);
+        let request = http::Request::builder().header("accept-language", "pt-BR").body(()).unwrap();
+        assert_eq!(service.call(request).await.unwrap(), Locale::Pt);
+        let request = http::Request::builder().body(()).unwrap();
+        assert_eq!(service.call(request).await.unwrap(), Locale::En);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::error::AppError;
+use crate::domain::{AuctionCurrency, AuctionStatus};
+
+// Catálogo en portugués
+pub fn message(err: &AppError) -> String {
+    use AppError::*;
+    match err {
+        Required { field } => format!("{} não pode estar vazio", field),
+        InvalidId { field } => format!("{} inválido", field),
+        InvalidNumber { field } => format!("{} deve ser um número válido", field),
+        NegativeValue { field } => format!("{} não pode ser negativo", field),
+        NotPositive { field } => format!("{} deve ser maior que zero", field),
+        TooManyDecimals { field, max_scale } => format!("{} admite no máximo {} casas decimais nesta moeda", field, max_scale),
+        TooLong { field, max } => format!("{} não pode exceder {} caracteres", field, max),
+        InvalidTimestamp { field } => format!("{} não é uma data válida", field),
+        InvalidStatus { .. } => format!(
+            "Status inválido. Valores permitidos: {}",
+            AuctionStatus::all_valid_statuses().join(", ")
+        ),
+        InvalidCurrency { .. } => format!(
+            "Moeda inválida. Valores permitidos: {}",
+            AuctionCurrency::all_valid_currencies().join(", ")
+        ),
+        InvalidDateRange => "A data de início deve ser anterior à data de término".to_string(),
+        StartTimeInPast => "A data de início não pode estar no passado".to_string(),
+        EndTimeInPast => "A data de término não pode estar no passado".to_string(),
+        ReserveBelowBasePrice => "reserve_price não pode ser menor que base_price".to_string(),
+        InvalidSoftClose => "extension_window_seconds, extension_seconds e max_extensions devem ser positivos para ativar o encerramento suave".to_string(),
+        AmountConflict => "Informe amount ou max_amount, não ambos".to_string(),
+        InvalidPageToken => "page_token inválido".to_string(),
+        InvalidSort => "sort inválido".to_string(),
+        PriceRangeInverted => "min_price não pode ser maior que max_price".to_string(),
+        InvalidIdempotencyKey => "idempotency_key deve ter entre 1 e 255 caracteres ASCII imprimíveis".to_string(),
+        IdempotencyKeyReused => "idempotency_key já foi usada com uma solicitação diferente".to_string(),
+        ImmutableField { field } => format!("{} não pode ser modificado", field),
+        UnknownUpdateField { path } => format!("update_mask contém um campo desconhecido: {}", path),
+        AuctionNotFound => "Leilão não encontrado".to_string(),
+        BidNotFound => "Lance não encontrado".to_string(),
+        NoBids => "Não há lances para este leilão".to_string(),
+        InvalidStatusTransition { from, to, allowed } => format!(
+            "Transição de status não permitida: '{}' -> '{}'. Transições permitidas a partir de '{}': {}",
+            from,
+            to,
+            from,
+            if allowed.is_empty() { "nenhuma (estado final)".to_string() } else { allowed.join(", ") }
+        ),
+        AuctionNotActive { status } => {
+            format!("O leilão deve estar no status 'active'. Status atual: '{}'", status)
+        },
+        AuctionEnded => "O leilão terminou".to_string(),
+        AuctionNotStarted => "O leilão ainda não começou".to_string(),
+        BidTooLow { min_required } => format!("O lance deve ser de pelo menos {}", min_required),
+        ProxyMaxNotExceeded => "Você já tem um lance automático ativo com um máximo igual ou superior".to_string(),
+        CancelRequiresForce { bid_count } => {
+            format!("O leilão tem {} lances; use force para cancelá-lo", bid_count)
+        },
+        DeleteWithBids { bid_count } => {
+            format!("Não é possível excluir um leilão com lances ({} registrados)", bid_count)
+        },
+        DeleteActiveAuction => "Não é possível excluir um leilão ativo; cancele-o primeiro".to_string(),
+        AuctionNotDeleted => "O leilão não está excluído".to_string(),
+        AuctionAlreadyCancelled => "O leilão já está cancelado".to_string(),
+        AuctionAlreadyClosed => "O leilão já está encerrado".to_string(),
+        AuctionNotClosed => "O leilão ainda não foi encerrado".to_string(),
+        BidNotRetractable { status } => {
+            format!("Somente lances vigentes podem ser retirados. Status atual: '{}'", status)
+        },
+        RetractionWindowExpired => "O prazo para retirar este lance já expirou".to_string(),
+        FieldNotEditable { field, status } => format!("{} só pode ser alterado enquanto o leilão está pendente (status atual: {})", field, status),
+        VersionConflict { expected, current } => format!(
+            "O leilão foi modificado por outra solicitação (versão esperada {}, versão atual {})",
+            expected, current
+        ),
+        RateLimited { .. } => format!(
+            "Muitos lances; tente novamente em {} segundos",
+            err.retry_after_secs().unwrap_or_default()
+        ),
+        MissingCredentials => "É necessário um token de acesso (authorization: Bearer <token>)".to_string(),
+        InvalidToken => "Token de acesso inválido ou expirado".to_string(),
+        NotBidOwner => "Somente quem fez o lance pode retirá-lo".to_string(),
+        CallerMismatch { field } => format!("{} não corresponde ao usuário autenticado", field),
+        NotAuctionOwner => "Somente o dono do leilão ou um administrador pode realizar esta operação".to_string(),
+        SelfBidding => "O vendedor não pode dar lances no próprio leilão".to_string(),
+        AdminRequired { field } => format!("{} requer permissões de administrador", field),
+        Database(_) => "Erro interno do servidor".to_string(),
+    }
+}
@@ -0,0 +1,257 @@
+use std::time::Duration;
+
+use prost::Message;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait,
+    QueryFilter, Set, SqlErr,
+};
+use tonic::Request;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::idempotency_key::{
+    ActiveModel as IdempotencyKeyActiveModel, Column as IdempotencyKeyColumn, Entity as IdempotencyKeyEntity,
+};
+
+// Por defecto una respuesta se puede repetir durante 24 horas
+pub const DEFAULT_TTL_SECS: u64 = 24 * 60 * 60;
+
+// Encabezado alternativo al campo idempotency_key del mensaje
+pub const HEADER: &str = "idempotency-key";
+
+const MAX_KEY_LEN: usize = 255;
+
+// Operaciones que aceptan clave de idempotencia
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    CreateAuction,
+    CreateBid,
+}
+
+impl Operation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Operation::CreateAuction => "create_auction",
+            Operation::CreateBid => "create_bid",
+        }
+    }
+}
+
+// Lee IDEMPOTENCY_TTL_SECS
+pub fn ttl_from_env() -> Duration {
+    let secs = std::env::var("IDEMPOTENCY_TTL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_TTL_SECS);
+    Duration::from_secs(secs)
+}
+
+// Valor del encabezado idempotency-key, si viene
+pub fn header_key<T>(request: &Request<T>) -> Option<String> {
+    request.metadata().get(HEADER).and_then(|v| v.to_str().ok()).map(str::to_string)
+}
+
+// Clave efectiva: el campo del mensaje o el encabezado. Si vienen ambos deben coincidir.
+pub fn resolve_key(field: String, header: Option<String>) -> Result<Option<String>, AppError> {
+    let key = match (field.is_empty(), header) {
+        (true, None) => return Ok(None),
+        (true, Some(header)) => header,
+        (false, Some(header)) if header != field => return Err(AppError::InvalidIdempotencyKey),
+        (false, _) => field,
+    };
+    if key.is_empty() || key.len() > MAX_KEY_LEN || !key.bytes().all(|b| b.is_ascii_graphic()) {
+        return Err(AppError::InvalidIdempotencyKey);
+    }
+    Ok(Some(key))
+}
+
+// Solicitud con clave de idempotencia. La clave es propia de cada usuario y
+// operación; `request` es el mensaje serializado sin la clave, para detectar
+// que se reutiliza con otros datos.
+pub struct IdempotentRequest {
+    user_id: String,
+    operation: Operation,
+    key: String,
+    request: Vec<u8>,
+}
+
+impl IdempotentRequest {
+    pub fn new<M: Message>(user_id: &str, operation: Operation, key: String, request: &M) -> Self {
+        IdempotentRequest { user_id: user_id.to_string(), operation, key, request: request.encode_to_vec() }
+    }
+
+    // Respuesta guardada por una solicitud anterior con la misma clave, si sigue vigente
+    pub async fn replay<C, M>(&self, db: &C, now: chrono::DateTime<chrono::Utc>) -> Result<Option<M>, AppError>
+    where
+        C: ConnectionTrait,
+        M: Message + Default,
+    {
+        let record = IdempotencyKeyEntity::find()
+            .filter(IdempotencyKeyColumn::UserId.eq(self.user_id.as_str()))
+            .filter(IdempotencyKeyColumn::Operation.eq(self.operation.as_str()))
+            .filter(IdempotencyKeyColumn::Key.eq(self.key.as_str()))
+            .filter(IdempotencyKeyColumn::ExpiresAt.gt(now))
+            .one(db)
+            .await?;
+        let Some(record) = record else {
+            return Ok(None);
+        };
+        if record.request != self.request {
+            log::warn!("Clave de idempotencia reutilizada con otra solicitud: user_id={}, operation={}, key={}",
+                self.user_id, self.operation.as_str(), self.key);
+            return Err(AppError::IdempotencyKeyReused);
+        }
+        log::info!("Repitiendo respuesta guardada: user_id={}, operation={}, key={}",
+            self.user_id, self.operation.as_str(), self.key);
+        M::decode(record.response.as_slice())
+            .map(Some)
+            .map_err(|e| DbErr::Custom(format!("Respuesta idempotente ilegible: {}", e)).into())
+    }
+
+    async fn save<M: Message>(
+        &self,
+        txn: &DatabaseTransaction,
+        response: &M,
+        now: chrono::DateTime<chrono::Utc>,
+        ttl: Duration,
+    ) -> Result<(), DbErr> {
+        // Una clave vencida que aún no se purgó puede volver a usarse
+        IdempotencyKeyEntity::delete_many()
+            .filter(IdempotencyKeyColumn::UserId.eq(self.user_id.as_str()))
+            .filter(IdempotencyKeyColumn::Operation.eq(self.operation.as_str()))
+            .filter(IdempotencyKeyColumn::Key.eq(self.key.as_str()))
+            .filter(IdempotencyKeyColumn::ExpiresAt.lte(now))
+            .exec(txn)
+            .await?;
+        IdempotencyKeyActiveModel {
+            id: Set(Uuid::new_v4()),
+            user_id: Set(self.user_id.clone()),
+            operation: Set(self.operation.as_str().to_string()),
+            key: Set(self.key.clone()),
+            request: Set(self.request.clone()),
+            response: Set(response.encode_to_vec()),
+            created_at: Set(now),
+            expires_at: Set(now + chrono::Duration::seconds(ttl.as_secs() as i64)),
+        }
+        .insert(txn)
+        .await?;
+        Ok(())
+    }
+}
+
+// Confirma `txn` guardando antes la respuesta bajo la clave, si la hay. Si una
+// solicitud concurrente con la misma clave se confirmó primero, la transacción
+// se descarta y se devuelve la respuesta de esa solicitud.
+pub async fn commit<M: Message + Default>(
+    txn: DatabaseTransaction,
+    db: &DatabaseConnection,
+    idempotent: Option<&IdempotentRequest>,
+    response: &M,
+    now: chrono::DateTime<chrono::Utc>,
+    ttl: Duration,
+) -> Result<Option<M>, AppError> {
+    if let Some(idempotent) = idempotent {
+        if let Err(e) = idempotent.save(&txn, response, now, ttl).await {
+            if !matches!(e.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) {
+                return Err(e.into());
+            }
+            txn.rollback().await?;
+            return match idempotent.replay(db, now).await? {
+                Some(previous) => Ok(Some(previous)),
+                None => Err(e.into()),
+            };
+        }
+    }
+    txn.commit().await?;
+    Ok(None)
+}
+
+// Elimina las claves vencidas
+pub async fn purge_expired<C: ConnectionTrait>(db: &C, now: chrono::DateTime<chrono::Utc>) -> Result<u64, DbErr> {
+    Ok(IdempotencyKeyEntity::delete_many()
+        .filter(IdempotencyKeyColumn::ExpiresAt.lte(now))
+        .exec(db)
+        .await?
+        .rows_affected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grpc_server::auction::CreateBidResponse;
+    use crate::test_utils::setup_test_db;
+    use sea_orm::TransactionTrait;
+
+    fn base_time() -> chrono::DateTime<chrono::Utc> {
+        chrono::NaiveDate::from_ymd_opt(2030, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc()
+    }
+
+    #[test]
+    fn test_resolve_key_from_field_or_header() {
+        assert_eq!(resolve_key(String::new(), None).unwrap(), None);
+        assert_eq!(resolve_key("k1".to_string(), None).unwrap().as_deref(), Some("k1"));
+        assert_eq!(resolve_key(String::new(), Some("k2".to_string())).unwrap().as_deref(), Some("k2"));
+        assert_eq!(resolve_key("k1".to_string(), Some("k1".to_string())).unwrap().as_deref(), Some("k1"));
+
+        let invalid = |field: &str, header: Option<&str>| {
+            matches!(
+                resolve_key(field.to_string(), header.map(str::to_string)),
+                Err(AppError::InvalidIdempotencyKey)
+            )
+        };
+        assert!(invalid("k1", Some("k2")));
+        assert!(invalid("con espacios", None));
+        assert!(invalid("", Some("")));
+        assert!(invalid(&"x".repeat(MAX_KEY_LEN + 1), None));
+    }
+
+    #[tokio::test]
+    async fn test_replay_within_ttl_and_purge_after() {
+        let db = setup_test_db().await;
+        let now = base_time();
+        let ttl = Duration::from_secs(60);
+        let request = IdempotentRequest::new("alice", Operation::CreateBid, "k1".to_string(), &"payload".to_string());
+        let response = CreateBidResponse { highest_bid: "150".to_string(), is_leading: true, ..Default::default() };
+
+        let txn = db.begin().await.unwrap();
+        assert!(commit(txn, &db, Some(&request), &response, now, ttl).await.unwrap().is_none());
+
+        let replayed: Option<CreateBidResponse> = request.replay(&db, now + chrono::Duration::seconds(59)).await.unwrap();
+        assert_eq!(replayed, Some(response.clone()));
+
+        // Misma clave con otro contenido
+        let other = IdempotentRequest::new("alice", Operation::CreateBid, "k1".to_string(), &"otro".to_string());
+        let err = other.replay::<_, CreateBidResponse>(&db, now).await.unwrap_err();
+        assert!(matches!(err, AppError::IdempotencyKeyReused));
+
+        // La clave es propia de cada usuario
+        let bob = IdempotentRequest::new("bob", Operation::CreateBid, "k1".to_string(), &"payload".to_string());
+        assert!(bob.replay::<_, CreateBidResponse>(&db, now).await.unwrap().is_none());
+
+        // Vencida ya no se repite y se puede volver a registrar
+        let later = now + chrono::Duration::seconds(60);
+        assert!(request.replay::<_, CreateBidResponse>(&db, later).await.unwrap().is_none());
+        let txn = db.begin().await.unwrap();
+        assert!(commit(txn, &db, Some(&request), &response, later, ttl).await.unwrap().is_none());
+
+        assert_eq!(purge_expired(&db, later + chrono::Duration::seconds(60)).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_commit_returns_first_response() {
+        let db = setup_test_db().await;
+        let now = base_time();
+        let ttl = Duration::from_secs(60);
+        let request = IdempotentRequest::new("alice", Operation::CreateBid, "k1".to_string(), &"payload".to_string());
+        let first = CreateBidResponse { highest_bid: "150".to_string(), ..Default::default() };
+        let second = CreateBidResponse { highest_bid: "160".to_string(), ..Default::default() };
+
+        let txn = db.begin().await.unwrap();
+        commit(txn, &db, Some(&request), &first, now, ttl).await.unwrap();
+        // La segunda solicitud no vio la clave al empezar y choca al guardarla
+        let txn = db.begin().await.unwrap();
+        let replayed = commit(txn, &db, Some(&request), &second, now, ttl).await.unwrap();
+        assert_eq!(replayed, Some(first));
+    }
+}
@@ -1,2 +1,26 @@
+// tonic::Status es grande, pero es el tipo de error natural de los handlers gRPC
+#![allow(clippy::result_large_err)]
+
+pub mod auth;
+pub mod config;
 pub mod db;
-pub mod models;
\ No newline at end of file
+pub mod domain;
+pub mod error;
+pub mod events;
+pub mod grpc_server;
+pub mod i18n;
+pub mod idempotency;
+pub mod listing;
+pub mod models;
+pub mod policy;
+pub mod proto_enums;
+pub mod proxy_bidding;
+pub mod rate_limit;
+pub mod retraction;
+pub mod scheduler;
+pub mod settlement;
+pub mod update_mask;
+pub mod validation;
+
+#[cfg(test)]
+mod test_utils;
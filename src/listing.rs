@@ -0,0 +1,438 @@
+use rust_decimal::Decimal;
+use sea_orm::sea_query::{Alias, Expr, Func, Query, SelectStatement, SimpleExpr, WindowStatement};
+use sea_orm::{
+    ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, LoaderTrait, Order, QueryFilter, QueryOrder,
+    QuerySelect, Select,
+};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::grpc_server::auction::{AuctionSort, ListAuctionsRequest};
+use crate::models::auction::{Column as AuctionColumn, Entity as AuctionEntity, Model as AuctionModel};
+use crate::models::bid::{Column as BidColumn, Entity as BidEntity, Model as BidModel};
+use crate::proto_enums::{auction_status_from_request, currency_from_request};
+
+pub const DEFAULT_PAGE_SIZE: u64 = 50;
+pub const MAX_PAGE_SIZE: u64 = 200;
+
+// Precio actual de la subasta: la puja más alta o el precio base si no hay pujas
+fn current_price_expr() -> SimpleExpr {
+    Func::coalesce([
+        Expr::col((AuctionEntity, AuctionColumn::HighestBid)).into(),
+        Expr::col((AuctionEntity, AuctionColumn::BasePrice)).into(),
+    ])
+    .into()
+}
+
+// Valor decimal comparable con columnas numéricas. SQLite recibe los Decimal como
+// texto y los compararía como tal, por eso se convierten explícitamente.
+fn decimal_value(value: Decimal) -> SimpleExpr {
+    Expr::val(value).cast_as(Alias::new("NUMERIC"))
+}
+
+// Clave de orden para HIGHEST_BID: las subastas sin pujas van al final
+fn highest_bid_expr() -> SimpleExpr {
+    Func::coalesce([
+        Expr::col((AuctionEntity, AuctionColumn::HighestBid)).into(),
+        decimal_value(Decimal::ZERO),
+    ])
+    .into()
+}
+
+fn sort_code(sort: AuctionSort) -> &'static str {
+    match sort {
+        AuctionSort::Newest => "newest",
+        AuctionSort::EndingSoonest => "ending",
+        AuctionSort::HighestBid => "highest",
+    }
+}
+
+// Cursor opaco: orden, valor de la clave de orden e id del último elemento devuelto
+#[derive(Debug, Clone, PartialEq)]
+struct Cursor {
+    value: String,
+    id: Uuid,
+}
+
+fn encode_cursor(sort: AuctionSort, last: &AuctionModel) -> String {
+    let value = match sort {
+        AuctionSort::Newest => last.start_time.timestamp_micros().to_string(),
+        AuctionSort::EndingSoonest => last.end_time.timestamp_micros().to_string(),
+        AuctionSort::HighestBid => last.highest_bid.unwrap_or_default().to_string(),
+    };
+    format!("{}|{}|{}", sort_code(sort), value, last.id)
+}
+
+fn decode_cursor(sort: AuctionSort, token: &str) -> Result<Cursor, AppError> {
+    let invalid = || AppError::InvalidPageToken;
+    let mut parts = token.splitn(3, '|');
+    let (Some(code), Some(value), Some(id)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(invalid());
+    };
+    if code != sort_code(sort) {
+        return Err(AppError::InvalidPageToken);
+    }
+    let id = Uuid::parse_str(id).map_err(|_| invalid())?;
+    Ok(Cursor { value: value.to_string(), id })
+}
+
+fn cursor_time(value: &str) -> Result<chrono::DateTime<chrono::Utc>, AppError> {
+    value
+        .parse::<i64>()
+        .ok()
+        .and_then(chrono::DateTime::from_timestamp_micros)
+        .ok_or(AppError::InvalidPageToken)
+}
+
+fn optional_time(ts: &Option<prost_types::Timestamp>, field: &'static str) -> Result<Option<chrono::DateTime<chrono::Utc>>, AppError> {
+    ts.as_ref()
+        .map(|t| {
+            chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32)
+                .ok_or(AppError::InvalidTimestamp { field })
+        })
+        .transpose()
+}
+
+fn optional_decimal(value: &str, field: &'static str) -> Result<Option<Decimal>, AppError> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    value
+        .parse::<Decimal>()
+        .map(Some)
+        .map_err(|_| AppError::InvalidNumber { field })
+}
+
+// Tamaño de página efectivo según lo solicitado
+pub fn page_size(req: &ListAuctionsRequest) -> Result<u64, AppError> {
+    match req.page_size {
+        0 => Ok(DEFAULT_PAGE_SIZE),
+        n if n < 0 => Err(AppError::NegativeValue { field: "page_size" }),
+        n => Ok((n as u64).min(MAX_PAGE_SIZE)),
+    }
+}
+
+// Límite de pujas por subasta; None cuando no se piden pujas o se quieren todas
+pub fn bids_limit(req: &ListAuctionsRequest) -> Result<Option<usize>, AppError> {
+    match req.max_bids_per_auction {
+        n if n < 0 => Err(AppError::NegativeValue { field: "max_bids_per_auction" }),
+        0 => Ok(None),
+        n => Ok(Some(n as usize)),
+    }
+}
+
+// Carga las pujas de una página de subastas en una sola consulta, de la más
+// reciente a la más antigua, en el mismo orden que `auctions`
+pub async fn load_bids<C: ConnectionTrait>(
+    db: &C,
+    auctions: &[AuctionModel],
+    limit: Option<usize>,
+) -> Result<Vec<Vec<BidModel>>, DbErr> {
+    if auctions.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut query = BidEntity::find().order_by_desc(BidColumn::CreatedAt);
+    if let Some(limit) = limit {
+        query = query.filter(BidColumn::Id.in_subquery(latest_bids(auctions, limit)));
+    }
+    auctions.load_many(query, db).await
+}
+
+// Ids de las `limit` pujas más recientes de cada subasta. El límite se aplica
+// en la base de datos para no leer todas las pujas de una subasta con muchas.
+fn latest_bids(auctions: &[AuctionModel], limit: usize) -> SelectStatement {
+    let rank = Alias::new("bid_rank");
+    let ranked = Query::select()
+        .column(BidColumn::Id)
+        .expr_window_as(
+            Expr::cust("ROW_NUMBER()"),
+            WindowStatement::partition_by(BidColumn::AuctionId)
+                .order_by(BidColumn::CreatedAt, Order::Desc)
+                .to_owned(),
+            rank.clone(),
+        )
+        .from(BidEntity)
+        .and_where(BidColumn::AuctionId.is_in(auctions.iter().map(|a| a.id)))
+        .to_owned();
+    Query::select()
+        .column(BidColumn::Id)
+        .from_subquery(ranked, Alias::new("ranked_bid"))
+        .and_where(Expr::col(rank).lte(limit as i64))
+        .to_owned()
+}
+
+// Construye la consulta de una página de subastas con filtros, orden y cursor
+// aplicados en la base de datos. Se pide un elemento extra para saber si hay más.
+pub fn build_query(req: &ListAuctionsRequest) -> Result<(Select<AuctionEntity>, u64), AppError> {
+    let size = page_size(req)?;
+    let sort = AuctionSort::try_from(req.sort).map_err(|_| AppError::InvalidSort)?;
+    let mut query = AuctionEntity::find();
+
+    if !req.include_deleted {
+        query = query.filter(AuctionColumn::DeletedAt.is_null());
+    }
+
+    if let Some(status) = auction_status_from_request(req.status_value, &req.status)? {
+        query = query.filter(AuctionColumn::Status.eq(status));
+    }
+    if !req.category.is_empty() {
+        query = query.filter(AuctionColumn::Category.eq(req.category.trim()));
+    }
+    if let Some(currency) = currency_from_request(req.currency_value, &req.currency)? {
+        query = query.filter(AuctionColumn::Currency.eq(currency));
+    }
+    if !req.user_id.is_empty() {
+        query = query.filter(AuctionColumn::UserId.eq(req.user_id.as_str()));
+    }
+
+    let min_price = optional_decimal(&req.min_price, "min_price")?;
+    let max_price = optional_decimal(&req.max_price, "max_price")?;
+    if let (Some(min), Some(max)) = (min_price, max_price) {
+        if min > max {
+            return Err(AppError::PriceRangeInverted);
+        }
+    }
+    if let Some(min) = min_price {
+        query = query.filter(Expr::expr(current_price_expr()).gte(decimal_value(min)));
+    }
+    if let Some(max) = max_price {
+        query = query.filter(Expr::expr(current_price_expr()).lte(decimal_value(max)));
+    }
+
+    if let Some(t) = optional_time(&req.start_after, "start_after")? {
+        query = query.filter(AuctionColumn::StartTime.gte(t));
+    }
+    if let Some(t) = optional_time(&req.start_before, "start_before")? {
+        query = query.filter(AuctionColumn::StartTime.lte(t));
+    }
+    if let Some(t) = optional_time(&req.end_after, "end_after")? {
+        query = query.filter(AuctionColumn::EndTime.gte(t));
+    }
+    if let Some(t) = optional_time(&req.end_before, "end_before")? {
+        query = query.filter(AuctionColumn::EndTime.lte(t));
+    }
+
+    let cursor = if req.page_token.is_empty() {
+        None
+    } else {
+        Some(decode_cursor(sort, &req.page_token)?)
+    };
+
+    query = match sort {
+        AuctionSort::Newest => {
+            if let Some(c) = &cursor {
+                let t = cursor_time(&c.value)?;
+                query = query.filter(
+                    Condition::any()
+                        .add(AuctionColumn::StartTime.lt(t))
+                        .add(Condition::all().add(AuctionColumn::StartTime.eq(t)).add(AuctionColumn::Id.lt(c.id))),
+                );
+            }
+            query.order_by(AuctionColumn::StartTime, Order::Desc).order_by(AuctionColumn::Id, Order::Desc)
+        },
+        AuctionSort::EndingSoonest => {
+            if let Some(c) = &cursor {
+                let t = cursor_time(&c.value)?;
+                query = query.filter(
+                    Condition::any()
+                        .add(AuctionColumn::EndTime.gt(t))
+                        .add(Condition::all().add(AuctionColumn::EndTime.eq(t)).add(AuctionColumn::Id.gt(c.id))),
+                );
+            }
+            query.order_by(AuctionColumn::EndTime, Order::Asc).order_by(AuctionColumn::Id, Order::Asc)
+        },
+        AuctionSort::HighestBid => {
+            if let Some(c) = &cursor {
+                let v = optional_decimal(&c.value, "page_token")?.unwrap_or_default();
+                query = query.filter(
+                    Condition::any()
+                        .add(Expr::expr(highest_bid_expr()).lt(decimal_value(v)))
+                        .add(Condition::all().add(Expr::expr(highest_bid_expr()).eq(decimal_value(v))).add(AuctionColumn::Id.lt(c.id))),
+                );
+            }
+            query.order_by(highest_bid_expr(), Order::Desc).order_by(AuctionColumn::Id, Order::Desc)
+        },
+    };
+
+    Ok((query.limit(size + 1), size))
+}
+
+// Recorta el elemento extra y genera el token de la página siguiente
+pub fn finish_page(req: &ListAuctionsRequest, mut auctions: Vec<AuctionModel>, size: u64) -> (Vec<AuctionModel>, String) {
+    let sort = AuctionSort::try_from(req.sort).unwrap_or(AuctionSort::Newest);
+    if auctions.len() as u64 <= size {
+        return (auctions, String::new());
+    }
+    auctions.truncate(size as usize);
+    let token = auctions.last().map(|last| encode_cursor(sort, last)).unwrap_or_default();
+    (auctions, token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AuctionCurrency, AuctionStatus};
+    use crate::models::auction::ActiveModel as AuctionActiveModel;
+    use crate::test_utils::setup_test_db;
+    use sea_orm::{ActiveModelTrait, DatabaseConnection, Set};
+
+    fn base_time() -> chrono::DateTime<chrono::Utc> {
+        chrono::NaiveDate::from_ymd_opt(2030, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc()
+    }
+
+    async fn insert_auction(
+        db: &DatabaseConnection,
+        hour: i64,
+        highest_bid: Option<i64>,
+        category: &str,
+        status: AuctionStatus,
+    ) -> AuctionModel {
+        let start = base_time() + chrono::Duration::hours(hour);
+        AuctionActiveModel {
+            id: Set(Uuid::new_v4()),
+            user_id: Set("seller".to_string()),
+            item_id: Set("item".to_string()),
+            title: Set(format!("Subasta {}", hour)),
+            description: Set(None),
+            category: Set(category.to_string()),
+            start_time: Set(start),
+            end_time: Set(start + chrono::Duration::days(1)),
+            base_price: Set(Decimal::from(100)),
+            min_bid_increment: Set(Decimal::from(10)),
+            highest_bid: Set(highest_bid.map(Decimal::from)),
+            status: Set(status),
+            currency: Set(AuctionCurrency::USD),
+            reserve_price: Set(None),
+            extension_window_secs: Set(0),
+            extension_secs: Set(0),
+            max_extensions: Set(0),
+            extensions_count: Set(0),
+            deleted_at: Set(None),
+            version: Set(1),
+        }
+        .insert(db)
+        .await
+        .unwrap()
+    }
+
+    async fn fetch_all_pages(db: &DatabaseConnection, mut req: ListAuctionsRequest) -> Vec<Vec<AuctionModel>> {
+        let mut pages = Vec::new();
+        for _ in 0..20 {
+            let (query, size) = build_query(&req).unwrap();
+            let (page, token) = finish_page(&req, query.all(db).await.unwrap(), size);
+            pages.push(page);
+            if token.is_empty() {
+                return pages;
+            }
+            req.page_token = token;
+        }
+        panic!("la paginación no terminó");
+    }
+
+    #[tokio::test]
+    async fn test_pagination_ending_soonest_visits_every_auction_once() {
+        let db = setup_test_db().await;
+        let mut expected = Vec::new();
+        for hour in [5, 1, 3, 2, 4] {
+            expected.push(insert_auction(&db, hour, None, "Electronics", AuctionStatus::Active).await);
+        }
+        expected.sort_by_key(|a| a.end_time);
+
+        let req = ListAuctionsRequest { page_size: 2, sort: AuctionSort::EndingSoonest as i32, ..Default::default() };
+        let pages = fetch_all_pages(&db, req).await;
+
+        assert_eq!(pages.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 2, 1]);
+        let ids: Vec<Uuid> = pages.into_iter().flatten().map(|a| a.id).collect();
+        assert_eq!(ids, expected.iter().map(|a| a.id).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_sort_by_highest_bid_with_cursor() {
+        let db = setup_test_db().await;
+        let none = insert_auction(&db, 1, None, "Electronics", AuctionStatus::Active).await;
+        let low = insert_auction(&db, 2, Some(150), "Electronics", AuctionStatus::Active).await;
+        let high = insert_auction(&db, 3, Some(900), "Electronics", AuctionStatus::Active).await;
+
+        let req = ListAuctionsRequest { page_size: 1, sort: AuctionSort::HighestBid as i32, ..Default::default() };
+        let ids: Vec<Uuid> = fetch_all_pages(&db, req).await.into_iter().flatten().map(|a| a.id).collect();
+        assert_eq!(ids, vec![high.id, low.id, none.id]);
+    }
+
+    #[tokio::test]
+    async fn test_newest_is_default_sort() {
+        let db = setup_test_db().await;
+        let older = insert_auction(&db, 1, None, "Electronics", AuctionStatus::Active).await;
+        let newer = insert_auction(&db, 2, None, "Electronics", AuctionStatus::Active).await;
+
+        let (query, size) = build_query(&ListAuctionsRequest::default()).unwrap();
+        let (page, token) = finish_page(&ListAuctionsRequest::default(), query.all(&db).await.unwrap(), size);
+        assert_eq!(page.iter().map(|a| a.id).collect::<Vec<_>>(), vec![newer.id, older.id]);
+        assert!(token.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_filters_are_applied_in_query() {
+        let db = setup_test_db().await;
+        let wanted = insert_auction(&db, 3, Some(300), "Art", AuctionStatus::Active).await;
+        insert_auction(&db, 1, Some(300), "Electronics", AuctionStatus::Active).await;
+        insert_auction(&db, 2, Some(300), "Art", AuctionStatus::Pending).await;
+        insert_auction(&db, 4, Some(50_000), "Art", AuctionStatus::Active).await;
+        insert_auction(&db, 9, Some(300), "Art", AuctionStatus::Active).await;
+
+        let req = ListAuctionsRequest {
+            status: "active".to_string(),
+            category: "Art".to_string(),
+            currency: "usd".to_string(),
+            user_id: "seller".to_string(),
+            min_price: "200".to_string(),
+            max_price: "1000".to_string(),
+            start_before: Some(prost_types::Timestamp {
+                seconds: (base_time() + chrono::Duration::hours(5)).timestamp(),
+                nanos: 0,
+            }),
+            ..Default::default()
+        };
+        let (query, size) = build_query(&req).unwrap();
+        let (page, _) = finish_page(&req, query.all(&db).await.unwrap(), size);
+        assert_eq!(page.iter().map(|a| a.id).collect::<Vec<_>>(), vec![wanted.id]);
+    }
+
+    #[tokio::test]
+    async fn test_price_filter_uses_base_price_without_bids() {
+        let db = setup_test_db().await;
+        let no_bids = insert_auction(&db, 1, None, "Art", AuctionStatus::Active).await;
+
+        let req = ListAuctionsRequest { max_price: "100".to_string(), ..Default::default() };
+        let (query, _) = build_query(&req).unwrap();
+        let ids: Vec<Uuid> = query.all(&db).await.unwrap().into_iter().map(|a| a.id).collect();
+        assert_eq!(ids, vec![no_bids.id]);
+    }
+
+    #[test]
+    fn test_invalid_requests_are_rejected() {
+        let bad = [
+            ListAuctionsRequest { page_size: -1, ..Default::default() },
+            ListAuctionsRequest { status: "open".to_string(), ..Default::default() },
+            ListAuctionsRequest { currency: "JPY".to_string(), ..Default::default() },
+            ListAuctionsRequest { min_price: "abc".to_string(), ..Default::default() },
+            ListAuctionsRequest { min_price: "10".to_string(), max_price: "5".to_string(), ..Default::default() },
+            ListAuctionsRequest { page_token: "basura".to_string(), ..Default::default() },
+            ListAuctionsRequest { sort: 99, ..Default::default() },
+        ];
+        for req in bad {
+            assert_eq!(build_query(&req).unwrap_err().code(), tonic::Code::InvalidArgument, "{:?}", req);
+        }
+
+        // Un token generado con otro orden no se acepta
+        let token = format!("ending|0|{}", Uuid::new_v4());
+        let req = ListAuctionsRequest { page_token: token, sort: AuctionSort::HighestBid as i32, ..Default::default() };
+        assert!(build_query(&req).is_err());
+    }
+
+    #[test]
+    fn test_page_size_is_capped() {
+        let req = ListAuctionsRequest { page_size: 10_000, ..Default::default() };
+        assert_eq!(page_size(&req).unwrap(), MAX_PAGE_SIZE);
+        assert_eq!(page_size(&ListAuctionsRequest::default()).unwrap(), DEFAULT_PAGE_SIZE);
+    }
+}
@@ -1,7 +1,4 @@
-mod grpc_server;
-mod config;
-mod models;
-mod db; 
+use auction_ms::{config, grpc_server};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -9,4 +6,4 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     log::info!("Iniciando microservicio auction_ms...");
     grpc_server::start_grpc_server().await?;
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.12
 
+use crate::domain::{AuctionCurrency, AuctionStatus};
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
@@ -11,20 +12,33 @@ pub struct Model {
     pub item_id: String,
     pub title: String,
     pub description: Option<String>,
-    pub start_time: DateTime,
-    pub end_time: DateTime,
+    pub start_time: DateTimeUtc,
+    pub end_time: DateTimeUtc,
     pub base_price: Decimal,
     pub min_bid_increment: Decimal,
     pub highest_bid: Option<Decimal>,
-    pub status: String,
-    pub currency: String,
+    pub status: AuctionStatus,
+    pub currency: AuctionCurrency,
     pub category: String,
+    pub reserve_price: Option<Decimal>,
+    pub extension_window_secs: i32,
+    pub extension_secs: i32,
+    pub max_extensions: i32,
+    pub extensions_count: i32,
+    pub deleted_at: Option<DateTimeUtc>,
+    pub version: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_one = "super::auction_closure::Entity")]
+    AuctionClosure,
+    #[sea_orm(has_one = "super::auction_result::Entity")]
+    AuctionResult,
     #[sea_orm(has_many = "super::bid::Entity")]
     Bid,
+    #[sea_orm(has_many = "super::proxy_bid::Entity")]
+    ProxyBid,
 }
 
 impl Related<super::bid::Entity> for Entity {
@@ -33,4 +47,22 @@ impl Related<super::bid::Entity> for Entity {
     }
 }
 
+impl Related<super::auction_closure::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuctionClosure.def()
+    }
+}
+
+impl Related<super::auction_result::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuctionResult.def()
+    }
+}
+
+impl Related<super::proxy_bid::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ProxyBid.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
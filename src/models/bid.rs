@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.12
 
+use crate::domain::BidStatus;
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
@@ -10,8 +11,10 @@ pub struct Model {
     pub auction_id: Uuid,
     pub user_id: String,
     pub amount: Decimal,
-    pub created_at: DateTime,
-    pub status: String,
+    pub created_at: DateTimeUtc,
+    pub status: BidStatus,
+    pub retracted_at: Option<DateTimeUtc>,
+    pub retraction_reason: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -24,6 +27,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Auction,
+    #[sea_orm(has_one = "super::auction_result::Entity")]
+    AuctionResult,
 }
 
 impl Related<super::auction::Entity> for Entity {
@@ -32,4 +37,10 @@ impl Related<super::auction::Entity> for Entity {
     }
 }
 
+impl Related<super::auction_result::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuctionResult.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
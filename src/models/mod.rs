@@ -3,4 +3,8 @@
 pub mod prelude;
 
 pub mod auction;
+pub mod auction_closure;
+pub mod auction_result;
 pub mod bid;
+pub mod idempotency_key;
+pub mod proxy_bid;
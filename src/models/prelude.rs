@@ -1,4 +1,8 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.12
 
 pub use super::auction::Entity as Auction;
+pub use super::auction_closure::Entity as AuctionClosure;
+pub use super::auction_result::Entity as AuctionResult;
 pub use super::bid::Entity as Bid;
+pub use super::idempotency_key::Entity as IdempotencyKey;
+pub use super::proxy_bid::Entity as ProxyBid;
@@ -0,0 +1,128 @@
+use crate::auth::Identity;
+use crate::error::AppError;
+use crate::models::auction::Model as AuctionModel;
+
+// Rol del token que permite actuar sobre cualquier subasta
+pub const ADMIN_ROLE: &str = "admin";
+
+// Operaciones sobre una subasta existente que requieren autorización
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    UpdateAuction,
+    DeleteAuction,
+    RestoreAuction,
+    CancelAuction,
+    CloseAuction,
+    PlaceBid,
+}
+
+// Reglas de autorización consultadas por cada handler:
+// - el dueño no puede pujar en su propia subasta, aunque sea administrador
+// - solo el dueño puede modificar, eliminar, restaurar, cancelar o cerrar su subasta
+// - un administrador puede hacer cualquier otra operación
+pub fn authorize(identity: &Identity, action: Action, auction: &AuctionModel) -> Result<(), AppError> {
+    let is_owner = identity.user_id == auction.user_id;
+    match action {
+        Action::PlaceBid if is_owner => Err(AppError::SelfBidding),
+        Action::PlaceBid => Ok(()),
+        _ if is_owner || identity.is_admin() => Ok(()),
+        _ => Err(AppError::NotAuctionOwner),
+    }
+}
+
+// Ver subastas eliminadas (include_deleted) es una operación de administración
+pub fn authorize_include_deleted(identity: &Identity) -> Result<(), AppError> {
+    if identity.is_admin() {
+        return Ok(());
+    }
+    Err(AppError::AdminRequired { field: "include_deleted" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AuctionCurrency, AuctionStatus};
+
+    fn auction(owner: &str) -> AuctionModel {
+        let now = chrono::Utc::now();
+        AuctionModel {
+            id: uuid::Uuid::new_v4(),
+            user_id: owner.to_string(),
+            item_id: "item".to_string(),
+            title: "t".to_string(),
+            description: None,
+            category: "Art".to_string(),
+            start_time: now,
+            end_time: now,
+            base_price: rust_decimal::Decimal::from(100),
+            min_bid_increment: rust_decimal::Decimal::from(10),
+            highest_bid: None,
+            status: AuctionStatus::Active,
+            currency: AuctionCurrency::USD,
+            reserve_price: None,
+            extension_window_secs: 0,
+            extension_secs: 0,
+            max_extensions: 0,
+            extensions_count: 0,
+            deleted_at: None,
+            version: 1,
+        }
+    }
+
+    fn user(user_id: &str, roles: &[&str]) -> Identity {
+        Identity { user_id: user_id.to_string(), roles: roles.iter().map(|r| r.to_string()).collect() }
+    }
+
+    const MUTATIONS: [Action; 5] = [
+        Action::UpdateAuction,
+        Action::DeleteAuction,
+        Action::RestoreAuction,
+        Action::CancelAuction,
+        Action::CloseAuction,
+    ];
+
+    #[test]
+    fn test_owner_can_mutate_own_auction() {
+        let auction = auction("seller");
+        for action in MUTATIONS {
+            assert!(authorize(&user("seller", &[]), action, &auction).is_ok(), "{:?}", action);
+        }
+    }
+
+    #[test]
+    fn test_other_users_cannot_mutate() {
+        let auction = auction("seller");
+        for action in MUTATIONS {
+            let err = authorize(&user("mallory", &[]), action, &auction).unwrap_err();
+            assert!(matches!(err, AppError::NotAuctionOwner), "{:?}", action);
+        }
+    }
+
+    #[test]
+    fn test_seller_cannot_bid_on_own_auction() {
+        let auction = auction("seller");
+        assert!(matches!(authorize(&user("seller", &[]), Action::PlaceBid, &auction), Err(AppError::SelfBidding)));
+        assert!(authorize(&user("bidder", &[]), Action::PlaceBid, &auction).is_ok());
+    }
+
+    #[test]
+    fn test_admin_can_act_on_anything() {
+        let auction = auction("seller");
+        let admin = user("support", &[ADMIN_ROLE]);
+        for action in MUTATIONS.into_iter().chain([Action::PlaceBid]) {
+            assert!(authorize(&admin, action, &auction).is_ok(), "{:?}", action);
+        }
+        // Un administrador dueño de la subasta tampoco puede pujar en ella
+        let owner_admin = user("seller", &[ADMIN_ROLE]);
+        assert!(matches!(authorize(&owner_admin, Action::PlaceBid, &auction), Err(AppError::SelfBidding)));
+        // Otros roles no otorgan permisos adicionales
+        assert!(authorize(&user("mallory", &["moderator"]), Action::DeleteAuction, &auction).is_err());
+    }
+
+    #[test]
+    fn test_only_admins_include_deleted() {
+        assert!(authorize_include_deleted(&user("support", &[ADMIN_ROLE])).is_ok());
+        let err = authorize_include_deleted(&user("seller", &["moderator"])).unwrap_err();
+        assert!(matches!(err, AppError::AdminRequired { field: "include_deleted" }));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::domain::{AuctionCurrency, AuctionStatus, BidStatus};
+use crate::error::AppError;
+use crate::grpc_server::auction as proto;
+
+// Conversiones entre los enums del dominio y los del protocolo gRPC. Los valores
+// UNSPECIFIED (0) indican que el cliente no envió el campo tipado.
+
+pub(crate) fn auction_status_to_proto(status: AuctionStatus) -> proto::AuctionStatus {
+    match status {
+        AuctionStatus::Pending => proto::AuctionStatus::Pending,
+        AuctionStatus::Active => proto::AuctionStatus::Active,
+        AuctionStatus::Completed => proto::AuctionStatus::Completed,
+        AuctionStatus::Cancelled => proto::AuctionStatus::Cancelled,
+    }
+}
+
+// Valor tipado del mensaje; None si no viene (UNSPECIFIED)
+pub(crate) fn auction_status_from_proto(value: i32) -> Result<Option<AuctionStatus>, AppError> {
+    match proto::AuctionStatus::try_from(value) {
+        Ok(proto::AuctionStatus::Unspecified) => Ok(None),
+        Ok(proto::AuctionStatus::Pending) => Ok(Some(AuctionStatus::Pending)),
+        Ok(proto::AuctionStatus::Active) => Ok(Some(AuctionStatus::Active)),
+        Ok(proto::AuctionStatus::Completed) => Ok(Some(AuctionStatus::Completed)),
+        Ok(proto::AuctionStatus::Cancelled) => Ok(Some(AuctionStatus::Cancelled)),
+        Err(_) => Err(AppError::InvalidStatus { value: value.to_string() }),
+    }
+}
+
+// Estado enviado por un cliente: el valor tipado o, para los clientes
+// anteriores, el texto. Si vienen ambos deben coincidir.
+pub(crate) fn auction_status_from_request(value: i32, text: &str) -> Result<Option<AuctionStatus>, AppError> {
+    let typed = auction_status_from_proto(value)?;
+    if text.is_empty() {
+        return Ok(typed);
+    }
+    let parsed = AuctionStatus::from_str(text)?;
+    match typed {
+        Some(typed) if typed != parsed => Err(AppError::InvalidStatus { value: text.to_string() }),
+        _ => Ok(Some(parsed)),
+    }
+}
+
+pub(crate) fn currency_to_proto(currency: AuctionCurrency) -> proto::Currency {
+    match currency {
+        AuctionCurrency::USD => proto::Currency::Usd,
+        AuctionCurrency::EUR => proto::Currency::Eur,
+        AuctionCurrency::CLP => proto::Currency::Clp,
+        AuctionCurrency::ARS => proto::Currency::Ars,
+        AuctionCurrency::BRL => proto::Currency::Brl,
+        AuctionCurrency::MXN => proto::Currency::Mxn,
+    }
+}
+
+// Valor tipado del mensaje; None si no viene (UNSPECIFIED)
+pub(crate) fn currency_from_proto(value: i32) -> Result<Option<AuctionCurrency>, AppError> {
+    match proto::Currency::try_from(value) {
+        Ok(proto::Currency::Unspecified) => Ok(None),
+        Ok(proto::Currency::Usd) => Ok(Some(AuctionCurrency::USD)),
+        Ok(proto::Currency::Eur) => Ok(Some(AuctionCurrency::EUR)),
+        Ok(proto::Currency::Clp) => Ok(Some(AuctionCurrency::CLP)),
+        Ok(proto::Currency::Ars) => Ok(Some(AuctionCurrency::ARS)),
+        Ok(proto::Currency::Brl) => Ok(Some(AuctionCurrency::BRL)),
+        Ok(proto::Currency::Mxn) => Ok(Some(AuctionCurrency::MXN)),
+        Err(_) => Err(AppError::InvalidCurrency { value: value.to_string() }),
+    }
+}
+
+// Moneda enviada por un cliente: el valor tipado o, para los clientes
+// anteriores, el texto. Si vienen ambos deben coincidir.
+pub(crate) fn currency_from_request(value: i32, text: &str) -> Result<Option<AuctionCurrency>, AppError> {
+    let typed = currency_from_proto(value)?;
+    if text.is_empty() {
+        return Ok(typed);
+    }
+    let parsed = AuctionCurrency::from_str(text)?;
+    match typed {
+        Some(typed) if typed != parsed => Err(AppError::InvalidCurrency { value: text.to_string() }),
+        _ => Ok(Some(parsed)),
+    }
+}
+
+pub(crate) fn bid_status_to_proto(status: BidStatus) -> proto::BidStatus {
+    match status {
+        BidStatus::Active => proto::BidStatus::Active,
+        BidStatus::Outbid => proto::BidStatus::Outbid,
+        BidStatus::Won => proto::BidStatus::Won,
+        BidStatus::Lost => proto::BidStatus::Lost,
+        BidStatus::Retracted => proto::BidStatus::Retracted,
+        BidStatus::Cancelled => proto::BidStatus::Cancelled,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::Iterable;
+
+    #[test]
+    fn test_typed_values_round_trip() {
+        for status in AuctionStatus::iter() {
+            assert_eq!(auction_status_from_proto(auction_status_to_proto(status) as i32).unwrap(), Some(status));
+        }
+        for currency in AuctionCurrency::iter() {
+            assert_eq!(currency_from_proto(currency_to_proto(currency) as i32).unwrap(), Some(currency));
+        }
+    }
+
+    #[test]
+    fn test_request_accepts_typed_or_legacy_text() {
+        let active = proto::AuctionStatus::Active as i32;
+        assert_eq!(auction_status_from_request(0, "").unwrap(), None);
+        assert_eq!(auction_status_from_request(active, "").unwrap(), Some(AuctionStatus::Active));
+        assert_eq!(auction_status_from_request(0, "ACTIVE").unwrap(), Some(AuctionStatus::Active));
+        assert_eq!(auction_status_from_request(active, "active").unwrap(), Some(AuctionStatus::Active));
+        assert!(auction_status_from_request(active, "cancelled").is_err());
+        assert!(auction_status_from_request(99, "").is_err());
+
+        let clp = proto::Currency::Clp as i32;
+        assert_eq!(currency_from_request(clp, "").unwrap(), Some(AuctionCurrency::CLP));
+        assert_eq!(currency_from_request(0, "clp").unwrap(), Some(AuctionCurrency::CLP));
+        assert!(matches!(currency_from_request(clp, "USD"), Err(AppError::InvalidCurrency { .. })));
+        assert!(matches!(currency_from_request(42, ""), Err(AppError::InvalidCurrency { .. })));
+    }
+}
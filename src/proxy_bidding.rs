@@ -0,0 +1,228 @@
+use rust_decimal::Decimal;
+
+// Origen del techo de un postor dentro de la resolución de pujas automáticas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CeilingKind {
+    // Puja automática (proxy) con un máximo oculto
+    Proxy,
+    // Puja manual que se está colocando ahora; su monto es literal
+    NewBid,
+    // Puja manual ya registrada que lidera la subasta
+    StandingBid,
+}
+
+// Lo máximo que un postor está dispuesto a pagar y desde cuándo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ceiling {
+    pub user_id: String,
+    pub amount: Decimal,
+    pub submitted_at: chrono::DateTime<chrono::Utc>,
+    pub kind: CeilingKind,
+}
+
+// Puja visible que debe registrarse en la tabla bid, en orden
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedBid {
+    pub user_id: String,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolution {
+    pub bids: Vec<PlannedBid>,
+    pub leader: String,
+    pub price: Decimal,
+    // Usuarios cuyas pujas automáticas ya no pueden superar el nuevo precio
+    pub exhausted: Vec<String>,
+}
+
+// Resuelve la competencia entre pujas automáticas al estilo eBay: el postor con el
+// techo más alto lidera pagando el segundo techo más el incremento mínimo (sin
+// superar su propio máximo). Ante techos iguales gana el que llegó primero.
+//
+// `current` es la puja líder actual (usuario y monto) si existe. Se asume que el
+// nuevo techo ya pasó las validaciones de monto mínimo de create_bid.
+pub fn resolve(
+    mut ceilings: Vec<Ceiling>,
+    current: Option<(&str, Decimal)>,
+    base_price: Decimal,
+    increment: Decimal,
+) -> Resolution {
+    ceilings.sort_by(|a, b| b.amount.cmp(&a.amount).then(a.submitted_at.cmp(&b.submitted_at)));
+    let mut seen = std::collections::HashSet::new();
+    ceilings.retain(|c| seen.insert(c.user_id.clone()));
+
+    let winner = &ceilings[0];
+    let runner_up = ceilings.get(1);
+    let current_price = current.map(|(_, amount)| amount);
+    let winner_leads = current.is_some_and(|(user, _)| user == winner.user_id);
+
+    let price = match (winner.kind, runner_up) {
+        // Una puja manual siempre se registra por su monto exacto
+        (CeilingKind::NewBid, _) | (CeilingKind::StandingBid, _) => winner.amount,
+        (CeilingKind::Proxy, Some(second)) if second.amount == winner.amount => winner.amount,
+        (CeilingKind::Proxy, Some(second)) => (second.amount + increment).max(base_price).min(winner.amount),
+        (CeilingKind::Proxy, None) if winner_leads => current_price.unwrap_or(base_price),
+        (CeilingKind::Proxy, None) => current_price
+            .map_or(base_price, |p| (p + increment).max(base_price))
+            .min(winner.amount),
+    };
+
+    let mut bids = Vec::new();
+    let mut last_amount = current_price.unwrap_or(Decimal::ZERO);
+
+    // La puja manual del solicitante queda registrada aunque sea superada
+    if let Some(new_bid) = ceilings.iter().skip(1).find(|c| c.kind == CeilingKind::NewBid) {
+        bids.push(PlannedBid { user_id: new_bid.user_id.clone(), amount: new_bid.amount });
+        last_amount = last_amount.max(new_bid.amount);
+    }
+
+    // El segundo postor automático agota su máximo antes de ser superado
+    if let Some(second) = runner_up {
+        if second.kind == CeilingKind::Proxy && second.amount > last_amount && second.amount < price {
+            bids.push(PlannedBid { user_id: second.user_id.clone(), amount: second.amount });
+            last_amount = second.amount;
+        }
+    }
+
+    let unchanged = winner_leads && current_price == Some(price);
+    if !unchanged && (winner.kind == CeilingKind::NewBid || price >= last_amount) {
+        bids.push(PlannedBid { user_id: winner.user_id.clone(), amount: price });
+    }
+
+    let exhausted = ceilings
+        .iter()
+        .skip(1)
+        .filter(|c| c.kind == CeilingKind::Proxy && c.amount < price + increment)
+        .map(|c| c.user_id.clone())
+        .collect();
+
+    Resolution { bids, leader: winner.user_id.clone(), price, exhausted }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(minute: u32) -> chrono::DateTime<chrono::Utc> {
+        chrono::NaiveDate::from_ymd_opt(2030, 1, 1).unwrap().and_hms_opt(12, minute, 0).unwrap().and_utc()
+    }
+
+    fn ceiling(user: &str, amount: i64, minute: u32, kind: CeilingKind) -> Ceiling {
+        Ceiling { user_id: user.to_string(), amount: Decimal::from(amount), submitted_at: at(minute), kind }
+    }
+
+    fn bid(user: &str, amount: i64) -> PlannedBid {
+        PlannedBid { user_id: user.to_string(), amount: Decimal::from(amount) }
+    }
+
+    #[test]
+    fn test_first_proxy_opens_at_base_price() {
+        let res = resolve(
+            vec![ceiling("alice", 200, 0, CeilingKind::Proxy)],
+            None,
+            Decimal::from(100),
+            Decimal::from(10),
+        );
+        assert_eq!(res.bids, vec![bid("alice", 100)]);
+        assert_eq!(res.leader, "alice");
+        assert_eq!(res.price, Decimal::from(100));
+        assert!(res.exhausted.is_empty());
+    }
+
+    #[test]
+    fn test_higher_proxy_pays_second_max_plus_increment() {
+        let res = resolve(
+            vec![
+                ceiling("alice", 200, 0, CeilingKind::Proxy),
+                ceiling("bob", 150, 1, CeilingKind::Proxy),
+            ],
+            Some(("alice", Decimal::from(100))),
+            Decimal::from(100),
+            Decimal::from(10),
+        );
+        assert_eq!(res.bids, vec![bid("bob", 150), bid("alice", 160)]);
+        assert_eq!(res.leader, "alice");
+        assert_eq!(res.exhausted, vec!["bob".to_string()]);
+    }
+
+    #[test]
+    fn test_new_proxy_overtakes_and_is_capped_by_own_max() {
+        let res = resolve(
+            vec![
+                ceiling("alice", 200, 0, CeilingKind::Proxy),
+                ceiling("bob", 205, 1, CeilingKind::Proxy),
+            ],
+            Some(("alice", Decimal::from(160))),
+            Decimal::from(100),
+            Decimal::from(10),
+        );
+        // bob no puede pagar 210, así que su máximo fija el precio
+        assert_eq!(res.bids, vec![bid("alice", 200), bid("bob", 205)]);
+        assert_eq!(res.leader, "bob");
+        assert_eq!(res.price, Decimal::from(205));
+        assert_eq!(res.exhausted, vec!["alice".to_string()]);
+    }
+
+    #[test]
+    fn test_equal_proxies_earliest_wins() {
+        let res = resolve(
+            vec![
+                ceiling("late", 150, 5, CeilingKind::Proxy),
+                ceiling("early", 150, 1, CeilingKind::Proxy),
+            ],
+            Some(("early", Decimal::from(100))),
+            Decimal::from(100),
+            Decimal::from(10),
+        );
+        assert_eq!(res.leader, "early");
+        assert_eq!(res.price, Decimal::from(150));
+        assert_eq!(res.bids, vec![bid("early", 150)]);
+        assert_eq!(res.exhausted, vec!["late".to_string()]);
+    }
+
+    #[test]
+    fn test_manual_bid_is_answered_by_proxy() {
+        let res = resolve(
+            vec![
+                ceiling("alice", 300, 0, CeilingKind::Proxy),
+                ceiling("carol", 250, 3, CeilingKind::NewBid),
+            ],
+            Some(("alice", Decimal::from(210))),
+            Decimal::from(100),
+            Decimal::from(10),
+        );
+        assert_eq!(res.bids, vec![bid("carol", 250), bid("alice", 260)]);
+        assert_eq!(res.leader, "alice");
+    }
+
+    #[test]
+    fn test_manual_bid_above_proxy_max_wins_at_literal_amount() {
+        let res = resolve(
+            vec![
+                ceiling("alice", 200, 0, CeilingKind::Proxy),
+                ceiling("carol", 250, 3, CeilingKind::NewBid),
+            ],
+            Some(("alice", Decimal::from(150))),
+            Decimal::from(100),
+            Decimal::from(10),
+        );
+        assert_eq!(res.bids, vec![bid("alice", 200), bid("carol", 250)]);
+        assert_eq!(res.leader, "carol");
+        assert_eq!(res.price, Decimal::from(250));
+        assert_eq!(res.exhausted, vec!["alice".to_string()]);
+    }
+
+    #[test]
+    fn test_leader_raising_own_max_changes_nothing_visible() {
+        let res = resolve(
+            vec![ceiling("alice", 500, 4, CeilingKind::Proxy)],
+            Some(("alice", Decimal::from(160))),
+            Decimal::from(100),
+            Decimal::from(10),
+        );
+        assert!(res.bids.is_empty());
+        assert_eq!(res.leader, "alice");
+        assert_eq!(res.price, Decimal::from(160));
+    }
+}
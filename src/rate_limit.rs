@@ -0,0 +1,373 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use prost::Message;
+use tonic::body::BoxBody;
+use tonic::codegen::{http, BoxFuture, Body as HttpBody, Service};
+use tonic::server::NamedService;
+use tonic::transport::Body;
+use tonic::Status;
+use uuid::Uuid;
+
+use crate::auth::Identity;
+use crate::error::AppError;
+use crate::grpc_server::auction::CreateBidRequest;
+use crate::scheduler::{Clock, SystemClock};
+
+// Por defecto cada usuario puede hacer ráfagas de 5 pujas y 1 por segundo
+// sostenida; cada subasta acepta ráfagas de 50 y 20 por segundo
+pub const DEFAULT_USER_BURST: u32 = 5;
+pub const DEFAULT_USER_PER_SEC: f64 = 1.0;
+pub const DEFAULT_AUCTION_BURST: u32 = 50;
+pub const DEFAULT_AUCTION_PER_SEC: f64 = 20.0;
+
+// Cada cuánto se descartan las cubetas que ya están llenas
+const PRUNE_INTERVAL_SECS: i64 = 60;
+
+// Ruta gRPC de CreateBid, el único método limitado
+const CREATE_BID_PATH: &str = "/auction.AuctionService/CreateBid";
+
+// Cubeta de fichas: admite ráfagas de hasta `burst` y se recarga a `per_second`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub burst: u32,
+    pub per_second: f64,
+}
+
+impl RateLimit {
+    fn from_env(burst_var: &str, rate_var: &str, burst: u32, per_second: f64) -> Option<Self> {
+        let burst = std::env::var(burst_var).ok().and_then(|v| v.parse().ok()).unwrap_or(burst);
+        let per_second = std::env::var(rate_var).ok().and_then(|v| v.parse().ok()).unwrap_or(per_second);
+        // 0 en cualquiera de los dos valores desactiva el límite
+        (burst > 0 && per_second > 0.0).then_some(RateLimit { burst, per_second })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum BucketKey {
+    User(String),
+    Auction(Uuid),
+}
+
+impl BucketKey {
+    fn scope(&self) -> &'static str {
+        match self {
+            BucketKey::User(_) => "user",
+            BucketKey::Auction(_) => "auction",
+        }
+    }
+}
+
+struct Bucket {
+    tokens: f64,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Default)]
+struct Buckets {
+    by_key: HashMap<BucketKey, Bucket>,
+    next_prune: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+// Limita CreateBid por usuario autenticado y por subasta. Lo aplica
+// `RateLimitService` antes de que la solicitud llegue al handler.
+pub struct BidRateLimiter {
+    per_user: Option<RateLimit>,
+    per_auction: Option<RateLimit>,
+    clock: Arc<dyn Clock>,
+    buckets: Mutex<Buckets>,
+}
+
+impl Default for BidRateLimiter {
+    // Sin límites; el servidor usa `from_env`
+    fn default() -> Self {
+        BidRateLimiter::new(None, None, Arc::new(SystemClock))
+    }
+}
+
+impl BidRateLimiter {
+    pub fn new(per_user: Option<RateLimit>, per_auction: Option<RateLimit>, clock: Arc<dyn Clock>) -> Self {
+        BidRateLimiter { per_user, per_auction, clock, buckets: Mutex::default() }
+    }
+
+    // Lee BID_RATE_LIMIT_USER_BURST, BID_RATE_LIMIT_USER_PER_SEC,
+    // BID_RATE_LIMIT_AUCTION_BURST y BID_RATE_LIMIT_AUCTION_PER_SEC
+    pub fn from_env() -> Self {
+        BidRateLimiter::new(
+            RateLimit::from_env(
+                "BID_RATE_LIMIT_USER_BURST",
+                "BID_RATE_LIMIT_USER_PER_SEC",
+                DEFAULT_USER_BURST,
+                DEFAULT_USER_PER_SEC,
+            ),
+            RateLimit::from_env(
+                "BID_RATE_LIMIT_AUCTION_BURST",
+                "BID_RATE_LIMIT_AUCTION_PER_SEC",
+                DEFAULT_AUCTION_BURST,
+                DEFAULT_AUCTION_PER_SEC,
+            ),
+            Arc::new(SystemClock),
+        )
+    }
+
+    // Consume una ficha de cada cubeta aplicable. Si alguna está vacía no se
+    // consume ninguna y se indica cuánto esperar.
+    pub fn check(&self, user_id: &str, auction_id: Uuid) -> Result<(), AppError> {
+        let now = self.clock.now();
+        let limits: Vec<(BucketKey, RateLimit)> = [
+            self.per_user.map(|l| (BucketKey::User(user_id.to_string()), l)),
+            self.per_auction.map(|l| (BucketKey::Auction(auction_id), l)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let mut guard = self.buckets.lock().unwrap();
+        // La limpieza recorre todas las cubetas, así que se hace como mucho una
+        // vez por intervalo y no en cada puja
+        if guard.next_prune.is_none_or(|at| now >= at) {
+            self.prune(&mut guard.by_key, now);
+            guard.next_prune = Some(now + chrono::Duration::seconds(PRUNE_INTERVAL_SECS));
+        }
+        let buckets = &mut guard.by_key;
+
+        let mut exhausted: Option<(&'static str, Duration)> = None;
+        for (key, limit) in &limits {
+            let bucket = buckets
+                .entry(key.clone())
+                .or_insert(Bucket { tokens: limit.burst as f64, updated_at: now });
+            let elapsed = (now - bucket.updated_at).to_std().unwrap_or_default().as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed * limit.per_second).min(limit.burst as f64);
+            bucket.updated_at = now;
+            if bucket.tokens < 1.0 {
+                let wait = Duration::from_secs_f64((1.0 - bucket.tokens) / limit.per_second);
+                if exhausted.is_none_or(|(_, longest)| wait > longest) {
+                    exhausted = Some((key.scope(), wait));
+                }
+            }
+        }
+        if let Some((scope, retry_after)) = exhausted {
+            log::warn!("Límite de pujas alcanzado ({}): user_id={}, auction_id={}", scope, user_id, auction_id);
+            return Err(AppError::RateLimited { scope, retry_after });
+        }
+
+        for (key, _) in &limits {
+            if let Some(bucket) = buckets.get_mut(key) {
+                bucket.tokens -= 1.0;
+            }
+        }
+        Ok(())
+    }
+
+    // Una cubeta que ya se habría recargado por completo equivale a no tenerla
+    fn prune(&self, buckets: &mut HashMap<BucketKey, Bucket>, now: chrono::DateTime<chrono::Utc>) {
+        buckets.retain(|key, bucket| {
+            let limit = match key {
+                BucketKey::User(_) => self.per_user,
+                BucketKey::Auction(_) => self.per_auction,
+            };
+            limit.is_some_and(|l| {
+                let elapsed = (now - bucket.updated_at).to_std().unwrap_or_default().as_secs_f64();
+                bucket.tokens + elapsed * l.per_second < l.burst as f64
+            })
+        });
+    }
+}
+
+// Envuelve el servicio gRPC para limitar CreateBid antes de llegar al handler.
+// Debe quedar dentro del interceptor de autenticación, que deja la identidad
+// del llamador en las extensiones; la subasta se lee del mensaje.
+#[derive(Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    limiter: Arc<BidRateLimiter>,
+}
+
+impl<S> RateLimitService<S> {
+    pub fn new(inner: S, limiter: BidRateLimiter) -> Self {
+        RateLimitService { inner, limiter: Arc::new(limiter) }
+    }
+}
+
+impl<S> Service<http::Request<Body>> for RateLimitService<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<BoxBody>, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<S::Response, S::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<Body>) -> Self::Future {
+        if req.uri().path() != CREATE_BID_PATH {
+            return Box::pin(self.inner.call(req));
+        }
+        // `self.inner` es el que quedó listo en poll_ready; el clon atiende la siguiente
+        let ready = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, ready);
+        let limiter = self.limiter.clone();
+        Box::pin(async move {
+            let (parts, mut body) = req.into_parts();
+            let mut message = Vec::new();
+            while let Some(chunk) = body.data().await {
+                match chunk {
+                    Ok(chunk) => message.extend_from_slice(&chunk),
+                    Err(e) => {
+                        log::warn!("No se pudo leer la solicitud CreateBid: {}", e);
+                        return Ok(Status::cancelled("Solicitud incompleta").to_http());
+                    },
+                }
+            }
+            let caller = parts.extensions.get::<Identity>();
+            if let (Some(identity), Some(auction_id)) = (caller, bid_auction_id(&message)) {
+                if let Err(err) = limiter.check(&identity.user_id, auction_id) {
+                    return Ok(Status::from(err).to_http());
+                }
+            }
+            inner.call(http::Request::from_parts(parts, Body::from(message))).await
+        })
+    }
+}
+
+impl<S: NamedService> NamedService for RateLimitService<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+// Subasta de un CreateBid sin comprimir (prefijo gRPC de 5 bytes y el mensaje).
+// Si no se puede leer la solicitud sigue: el handler responde el error que corresponda.
+fn bid_auction_id(frame: &[u8]) -> Option<Uuid> {
+    let (prefix, message) = frame.split_first_chunk::<5>()?;
+    let [0, length @ ..] = prefix else {
+        return None;
+    };
+    let message = message.get(..u32::from_be_bytes(*length) as usize)?;
+    let request = CreateBidRequest::decode(message).ok()?;
+    Uuid::parse_str(&request.auction_id).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::FakeClock;
+
+    fn limiter(per_user: Option<RateLimit>, per_auction: Option<RateLimit>) -> (BidRateLimiter, Arc<FakeClock>) {
+        let t0 = chrono::NaiveDate::from_ymd_opt(2030, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc();
+        let clock = Arc::new(FakeClock::new(t0));
+        (BidRateLimiter::new(per_user, per_auction, clock.clone()), clock)
+    }
+
+    fn retry_after(result: Result<(), AppError>) -> (&'static str, Duration) {
+        match result {
+            Err(AppError::RateLimited { scope, retry_after }) => (scope, retry_after),
+            other => panic!("se esperaba RateLimited: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_user_burst_then_refill() {
+        let (limiter, clock) = limiter(Some(RateLimit { burst: 2, per_second: 0.5 }), None);
+        let auction = Uuid::new_v4();
+        assert!(limiter.check("alice", auction).is_ok());
+        assert!(limiter.check("alice", auction).is_ok());
+        let (scope, wait) = retry_after(limiter.check("alice", auction));
+        assert_eq!(scope, "user");
+        assert_eq!(wait, Duration::from_secs(2));
+
+        // Otro usuario tiene su propia cubeta
+        assert!(limiter.check("bob", auction).is_ok());
+
+        // Tras 1 segundo falta la mitad de una ficha; tras 2 ya hay una
+        clock.advance(chrono::Duration::seconds(1));
+        assert_eq!(retry_after(limiter.check("alice", auction)).1, Duration::from_secs(1));
+        clock.advance(chrono::Duration::seconds(1));
+        assert!(limiter.check("alice", auction).is_ok());
+        assert!(limiter.check("alice", auction).is_err());
+    }
+
+    #[test]
+    fn test_auction_limit_is_shared_by_all_bidders() {
+        let (limiter, clock) = limiter(
+            Some(RateLimit { burst: 10, per_second: 10.0 }),
+            Some(RateLimit { burst: 3, per_second: 1.0 }),
+        );
+        let hot = Uuid::new_v4();
+        for user in ["a", "b", "c"] {
+            assert!(limiter.check(user, hot).is_ok());
+        }
+        assert_eq!(retry_after(limiter.check("d", hot)), ("auction", Duration::from_secs(1)));
+        // Otra subasta no se ve afectada
+        assert!(limiter.check("d", Uuid::new_v4()).is_ok());
+
+        clock.advance(chrono::Duration::seconds(1));
+        assert!(limiter.check("d", hot).is_ok());
+    }
+
+    #[test]
+    fn test_rejected_bid_does_not_consume_other_bucket() {
+        let (limiter, _clock) = limiter(
+            Some(RateLimit { burst: 1, per_second: 1.0 }),
+            Some(RateLimit { burst: 1, per_second: 1.0 }),
+        );
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        assert!(limiter.check("alice", first).is_ok());
+        // Rechazada por la cubeta de alice: la subasta `second` conserva su ficha
+        assert!(limiter.check("alice", second).is_err());
+        assert!(limiter.check("bob", second).is_ok());
+    }
+
+    #[test]
+    fn test_disabled_limits_allow_everything() {
+        let (limiter, _clock) = limiter(None, None);
+        let auction = Uuid::new_v4();
+        for _ in 0..1000 {
+            assert!(limiter.check("alice", auction).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_full_buckets_are_pruned_once_per_interval() {
+        let (limiter, clock) = limiter(Some(RateLimit { burst: 1, per_second: 1.0 }), None);
+        let auction = Uuid::new_v4();
+        assert!(limiter.check("alice", auction).is_ok());
+        assert!(limiter.check("bob", auction).is_ok());
+        assert_eq!(limiter.buckets.lock().unwrap().by_key.len(), 2);
+
+        // Las cubetas ya están llenas, pero se conservan hasta la próxima limpieza
+        clock.advance(chrono::Duration::seconds(PRUNE_INTERVAL_SECS - 1));
+        assert!(limiter.check("carol", auction).is_ok());
+        assert_eq!(limiter.buckets.lock().unwrap().by_key.len(), 3);
+
+        clock.advance(chrono::Duration::seconds(1));
+        assert!(limiter.check("dave", auction).is_ok());
+        assert_eq!(limiter.buckets.lock().unwrap().by_key.len(), 1);
+    }
+
+    #[test]
+    fn test_bid_auction_id_reads_the_grpc_frame() {
+        let auction = Uuid::new_v4();
+        let encoded = CreateBidRequest { auction_id: auction.to_string(), ..Default::default() }.encode_to_vec();
+        let mut frame = vec![0u8];
+        frame.extend_from_slice(&(encoded.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&encoded);
+        assert_eq!(bid_auction_id(&frame), Some(auction));
+
+        // Comprimido, truncado o con un id inválido: lo resuelve el handler
+        let mut compressed = frame.clone();
+        compressed[0] = 1;
+        assert_eq!(bid_auction_id(&compressed), None);
+        assert_eq!(bid_auction_id(&frame[..frame.len() - 1]), None);
+        assert_eq!(bid_auction_id(&[]), None);
+        let invalid = CreateBidRequest { auction_id: "x".to_string(), ..Default::default() }.encode_to_vec();
+        let mut frame = vec![0u8];
+        frame.extend_from_slice(&(invalid.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&invalid);
+        assert_eq!(bid_auction_id(&frame), None);
+    }
+}
@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+use crate::domain::BidStatus;
+use crate::models::auction::{Column as AuctionColumn, Entity as AuctionEntity};
+use crate::models::bid::{Column as BidColumn, Entity as BidEntity, Model as BidModel};
+
+// Por defecto se puede retractar una puja durante la primera hora, o en cualquier
+// momento si faltan más de 12 horas para el cierre
+pub const DEFAULT_WINDOW_SECS: u64 = 3600;
+pub const DEFAULT_CUTOFF_SECS: u64 = 12 * 3600;
+
+// Reglas para retractar pujas: se permite dentro de `window` desde que se
+// colocó la puja, o mientras falte al menos `cutoff` para el fin de la subasta
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetractionPolicy {
+    pub window: Duration,
+    pub cutoff: Duration,
+}
+
+impl Default for RetractionPolicy {
+    fn default() -> Self {
+        RetractionPolicy {
+            window: Duration::from_secs(DEFAULT_WINDOW_SECS),
+            cutoff: Duration::from_secs(DEFAULT_CUTOFF_SECS),
+        }
+    }
+}
+
+fn secs_from_env(name: &str, default: u64) -> Duration {
+    let secs = std::env::var(name)
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(default);
+    Duration::from_secs(secs)
+}
+
+impl RetractionPolicy {
+    // Lee BID_RETRACTION_WINDOW_SECS y BID_RETRACTION_CUTOFF_SECS; 0 desactiva la regla
+    pub fn from_env() -> Self {
+        RetractionPolicy {
+            window: secs_from_env("BID_RETRACTION_WINDOW_SECS", DEFAULT_WINDOW_SECS),
+            cutoff: secs_from_env("BID_RETRACTION_CUTOFF_SECS", DEFAULT_CUTOFF_SECS),
+        }
+    }
+
+    pub fn allows(
+        &self,
+        bid_created_at: chrono::DateTime<chrono::Utc>,
+        end_time: chrono::DateTime<chrono::Utc>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> bool {
+        let within_window = !self.window.is_zero()
+            && now - bid_created_at <= chrono::Duration::from_std(self.window).unwrap_or(chrono::Duration::MAX);
+        let before_cutoff = !self.cutoff.is_zero()
+            && end_time - now >= chrono::Duration::from_std(self.cutoff).unwrap_or(chrono::Duration::MAX);
+        within_window || before_cutoff
+    }
+}
+
+// Recalcula la puja líder tras una retractación: entre las pujas vigentes
+// (activas o superadas) la más alta, y la más antigua en empate, vuelve a quedar
+// activa y su monto pasa a ser highest_bid. Sin pujas vigentes highest_bid queda vacío.
+pub async fn recompute_highest_bid<C: ConnectionTrait>(db: &C, auction_id: Uuid) -> Result<Option<BidModel>, DbErr> {
+    let leader = BidEntity::find()
+        .filter(BidColumn::AuctionId.eq(auction_id))
+        .filter(BidColumn::Status.is_in([BidStatus::Active, BidStatus::Outbid]))
+        .order_by_desc(BidColumn::Amount)
+        .order_by_asc(BidColumn::CreatedAt)
+        .one(db)
+        .await?;
+
+    if let Some(leader) = &leader {
+        BidEntity::update_many()
+            .col_expr(BidColumn::Status, Expr::value(BidStatus::Outbid))
+            .filter(BidColumn::AuctionId.eq(auction_id))
+            .filter(BidColumn::Status.eq(BidStatus::Active))
+            .filter(BidColumn::Id.ne(leader.id))
+            .exec(db)
+            .await?;
+        BidEntity::update_many()
+            .col_expr(BidColumn::Status, Expr::value(BidStatus::Active))
+            .filter(BidColumn::Id.eq(leader.id))
+            .exec(db)
+            .await?;
+    }
+
+    AuctionEntity::update_many()
+        .col_expr(AuctionColumn::HighestBid, Expr::value(leader.as_ref().map(|b| b.amount)))
+        .col_expr(AuctionColumn::Version, Expr::col(AuctionColumn::Version).add(1))
+        .filter(AuctionColumn::Id.eq(auction_id))
+        .exec(db)
+        .await?;
+    Ok(leader.map(|b| BidModel { status: BidStatus::Active, ..b }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u32, minute: u32) -> chrono::DateTime<chrono::Utc> {
+        chrono::NaiveDate::from_ymd_opt(2030, 1, 1).unwrap().and_hms_opt(hour, minute, 0).unwrap().and_utc()
+    }
+
+    fn policy(window_mins: u64, cutoff_hours: u64) -> RetractionPolicy {
+        RetractionPolicy {
+            window: Duration::from_secs(window_mins * 60),
+            cutoff: Duration::from_secs(cutoff_hours * 3600),
+        }
+    }
+
+    #[test]
+    fn test_retraction_within_window() {
+        let p = policy(60, 12);
+        // Faltan menos de 12 horas, pero la puja tiene 30 minutos
+        assert!(p.allows(at(10, 0), at(18, 0), at(10, 30)));
+        // Justo en el límite de la ventana
+        assert!(p.allows(at(10, 0), at(18, 0), at(11, 0)));
+        assert!(!p.allows(at(10, 0), at(18, 0), at(11, 1)));
+    }
+
+    #[test]
+    fn test_retraction_before_cutoff() {
+        let p = policy(60, 6);
+        // La puja es antigua, pero faltan al menos 6 horas para el cierre
+        assert!(p.allows(at(0, 0), at(18, 0), at(12, 0)));
+        assert!(!p.allows(at(0, 0), at(18, 0), at(12, 1)));
+    }
+
+    #[test]
+    fn test_zero_disables_rule() {
+        let p = policy(0, 0);
+        assert!(!p.allows(at(10, 0), at(23, 0), at(10, 0)));
+        let window_only = policy(60, 0);
+        assert!(!window_only.allows(at(0, 0), at(23, 0), at(2, 0)));
+    }
+}
@@ -0,0 +1,289 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QuerySelect};
+use tokio::task::JoinHandle;
+
+use crate::events::{self, EventBus};
+use crate::domain::AuctionStatus;
+use crate::models::auction::{Column as AuctionColumn, Entity as AuctionEntity};
+
+// Intervalo por defecto entre revisiones del ciclo de vida de las subastas
+pub const DEFAULT_TICK_SECS: u64 = 30;
+
+// Fuente de la hora actual, inyectable para poder probar el scheduler
+pub trait Clock: Send + Sync {
+    fn now(&self) -> chrono::DateTime<chrono::Utc>;
+}
+
+// Reloj real basado en UTC
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> chrono::DateTime<chrono::Utc> {
+        chrono::Utc::now()
+    }
+}
+
+// Resultado de una revisión: cuántas subastas cambiaron de estado
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TickResult {
+    pub activated: u64,
+    pub completed: u64,
+}
+
+// Lee el intervalo de revisión desde LIFECYCLE_TICK_SECS (en segundos)
+pub fn tick_interval_from_env() -> Duration {
+    let secs = std::env::var("LIFECYCLE_TICK_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_TICK_SECS);
+    Duration::from_secs(secs)
+}
+
+// Ejecuta una revisión del ciclo de vida:
+// - pending -> active cuando llegó start_time
+// - active -> completed cuando pasó end_time, registrando el ganador
+// Se activa primero para que una subasta cuyo periodo completo ocurrió durante
+// una caída del servicio quede cerrada en la misma revisión.
+pub async fn run_lifecycle_tick(
+    db: &DatabaseConnection,
+    events: &EventBus,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<TickResult, DbErr> {
+    let starting: Vec<uuid::Uuid> = AuctionEntity::find()
+        .select_only()
+        .column(AuctionColumn::Id)
+        .filter(AuctionColumn::Status.eq(AuctionStatus::Pending))
+        .filter(AuctionColumn::StartTime.lte(now))
+        .filter(AuctionColumn::DeletedAt.is_null())
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    // Cada subasta se activa por separado para publicar el evento solo si esta
+    // revisión la cambió (otra operación, como una cancelación, pudo adelantarse)
+    let mut activated = 0;
+    for auction_id in starting {
+        let updated = AuctionEntity::update_many()
+            .col_expr(AuctionColumn::Status, AuctionColumn::Status.save_as(Expr::val(AuctionStatus::Active)))
+            .col_expr(AuctionColumn::Version, Expr::col(AuctionColumn::Version).add(1))
+            .filter(AuctionColumn::Id.eq(auction_id))
+            .filter(AuctionColumn::Status.eq(AuctionStatus::Pending))
+            .filter(AuctionColumn::DeletedAt.is_null())
+            .exec(db)
+            .await?
+            .rows_affected;
+        if updated > 0 {
+            publish_status(events, auction_id, AuctionStatus::Active);
+            activated += updated;
+        }
+    }
+
+    // Cada cierre se liquida en su propia transacción
+    let expired: Vec<uuid::Uuid> = AuctionEntity::find()
+        .select_only()
+        .column(AuctionColumn::Id)
+        .filter(AuctionColumn::Status.eq(AuctionStatus::Active))
+        .filter(AuctionColumn::EndTime.lte(now))
+        .filter(AuctionColumn::DeletedAt.is_null())
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    let mut completed = 0;
+    for auction_id in expired {
+        if crate::settlement::complete_auction(db, auction_id, now).await?.is_some() {
+            publish_status(events, auction_id, AuctionStatus::Completed);
+            completed += 1;
+        }
+    }
+
+    Ok(TickResult { activated, completed })
+}
+
+fn publish_status(events: &EventBus, auction_id: uuid::Uuid, status: AuctionStatus) {
+    events.publish(events::status_changed(auction_id, status));
+}
+
+// Lanza la tarea en segundo plano. El primer tick ocurre de inmediato, lo que
+// pone al día las subastas que debieron cambiar de estado mientras el servicio
+// estaba detenido.
+pub fn spawn_lifecycle_scheduler(
+    db: DatabaseConnection,
+    events: EventBus,
+    clock: Arc<dyn Clock>,
+    tick: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        log::info!("Scheduler de ciclo de vida iniciado (intervalo: {:?})", tick);
+        let mut interval = tokio::time::interval(tick);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            match run_lifecycle_tick(&db, &events, clock.now()).await {
+                Ok(result) if result.activated > 0 || result.completed > 0 => {
+                    log::info!("Ciclo de vida: {} subastas activadas, {} completadas",
+                        result.activated, result.completed);
+                },
+                Ok(_) => log::debug!("Ciclo de vida: sin cambios"),
+                Err(e) => log::error!("Error en revisión del ciclo de vida: {}", e),
+            }
+            match crate::idempotency::purge_expired(&db, clock.now()).await {
+                Ok(0) => {},
+                Ok(purged) => log::debug!("Claves de idempotencia vencidas eliminadas: {}", purged),
+                Err(e) => log::error!("Error al eliminar claves de idempotencia vencidas: {}", e),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::auction::{ActiveModel as AuctionActiveModel, Model as AuctionModel};
+    use crate::domain::AuctionCurrency;
+    use crate::test_utils::{setup_test_db, FakeClock};
+    use sea_orm::{ActiveModelTrait, Set};
+
+    fn base_time() -> chrono::DateTime<chrono::Utc> {
+        chrono::NaiveDate::from_ymd_opt(2030, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc()
+    }
+
+    async fn insert_auction(
+        db: &DatabaseConnection,
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+        status: AuctionStatus,
+    ) -> AuctionModel {
+        AuctionActiveModel {
+            id: Set(uuid::Uuid::new_v4()),
+            user_id: Set("seller".to_string()),
+            item_id: Set("item".to_string()),
+            title: Set("Test".to_string()),
+            description: Set(None),
+            category: Set("Electronics".to_string()),
+            start_time: Set(start),
+            end_time: Set(end),
+            base_price: Set(rust_decimal::Decimal::from(100)),
+            min_bid_increment: Set(rust_decimal::Decimal::from(10)),
+            highest_bid: Set(None),
+            status: Set(status),
+            currency: Set(AuctionCurrency::USD),
+            reserve_price: Set(None),
+            extension_window_secs: Set(0),
+            extension_secs: Set(0),
+            max_extensions: Set(0),
+            extensions_count: Set(0),
+            deleted_at: Set(None),
+            version: Set(1),
+        }
+        .insert(db)
+        .await
+        .unwrap()
+    }
+
+    async fn status_of(db: &DatabaseConnection, auction: &AuctionModel) -> AuctionStatus {
+        AuctionEntity::find_by_id(auction.id).one(db).await.unwrap().unwrap().status
+    }
+
+    #[tokio::test]
+    async fn test_tick_activates_and_completes() {
+        let db = setup_test_db().await;
+        let clock = FakeClock::new(base_time());
+        let t0 = base_time();
+        let auction = insert_auction(&db, t0 + chrono::Duration::minutes(5), t0 + chrono::Duration::minutes(10), AuctionStatus::Pending).await;
+
+        // Antes de start_time no cambia nada
+        let result = run_lifecycle_tick(&db, &EventBus::default(), clock.now()).await.unwrap();
+        assert_eq!(result, TickResult::default());
+        assert_eq!(status_of(&db, &auction).await, AuctionStatus::Pending);
+
+        // Al llegar start_time se activa
+        clock.advance(chrono::Duration::minutes(5));
+        let result = run_lifecycle_tick(&db, &EventBus::default(), clock.now()).await.unwrap();
+        assert_eq!(result, TickResult { activated: 1, completed: 0 });
+        assert_eq!(status_of(&db, &auction).await, AuctionStatus::Active);
+
+        // Al pasar end_time se completa
+        clock.advance(chrono::Duration::minutes(5));
+        let result = run_lifecycle_tick(&db, &EventBus::default(), clock.now()).await.unwrap();
+        assert_eq!(result, TickResult { activated: 0, completed: 1 });
+        assert_eq!(status_of(&db, &auction).await, AuctionStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_tick_catches_up_after_downtime() {
+        let db = setup_test_db().await;
+        let t0 = base_time();
+        // Subasta cuyo periodo completo ocurrió mientras el servicio estaba caído
+        let missed = insert_auction(&db, t0, t0 + chrono::Duration::hours(1), AuctionStatus::Pending).await;
+        // Subasta activa que debió cerrarse
+        let overdue = insert_auction(&db, t0, t0 + chrono::Duration::hours(2), AuctionStatus::Active).await;
+
+        let result = run_lifecycle_tick(&db, &EventBus::default(), t0 + chrono::Duration::days(1)).await.unwrap();
+        assert_eq!(result, TickResult { activated: 1, completed: 2 });
+        assert_eq!(status_of(&db, &missed).await, AuctionStatus::Completed);
+        assert_eq!(status_of(&db, &overdue).await, AuctionStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_tick_ignores_cancelled_and_completed() {
+        let db = setup_test_db().await;
+        let t0 = base_time();
+        let cancelled = insert_auction(&db, t0, t0 + chrono::Duration::hours(1), AuctionStatus::Cancelled).await;
+        let completed = insert_auction(&db, t0, t0 + chrono::Duration::hours(1), AuctionStatus::Completed).await;
+
+        let result = run_lifecycle_tick(&db, &EventBus::default(), t0 + chrono::Duration::days(1)).await.unwrap();
+        assert_eq!(result, TickResult::default());
+        assert_eq!(status_of(&db, &cancelled).await, AuctionStatus::Cancelled);
+        assert_eq!(status_of(&db, &completed).await, AuctionStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_tick_skips_deleted_auctions() {
+        let db = setup_test_db().await;
+        let t0 = base_time();
+        let deleted = insert_auction(&db, t0, t0 + chrono::Duration::hours(1), AuctionStatus::Pending).await;
+        // Subasta activa eliminada que ya debió cerrarse
+        let deleted_active = insert_auction(&db, t0, t0 + chrono::Duration::hours(1), AuctionStatus::Active).await;
+        for auction in [&deleted, &deleted_active] {
+            let mut active: AuctionActiveModel = auction.clone().into();
+            active.deleted_at = Set(Some(t0));
+            active.update(&db).await.unwrap();
+        }
+
+        let events = EventBus::default();
+        let mut receiver = events.receiver();
+        let result = run_lifecycle_tick(&db, &events, t0 + chrono::Duration::days(1)).await.unwrap();
+        assert_eq!(result, TickResult::default());
+        assert_eq!(status_of(&db, &deleted).await, AuctionStatus::Pending);
+        assert_eq!(status_of(&db, &deleted_active).await, AuctionStatus::Active);
+        assert!(receiver.try_recv().is_err(), "no se publican eventos de subastas eliminadas");
+
+        let result = crate::settlement::complete_auction(&db, deleted_active.id, t0 + chrono::Duration::days(1)).await.unwrap();
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_spawned_scheduler_runs_on_startup() {
+        let db = setup_test_db().await;
+        let t0 = base_time();
+        let auction = insert_auction(&db, t0, t0 + chrono::Duration::hours(1), AuctionStatus::Pending).await;
+
+        let clock = Arc::new(FakeClock::new(t0 + chrono::Duration::minutes(1)));
+        let handle = spawn_lifecycle_scheduler(db.clone(), EventBus::default(), clock, Duration::from_secs(3600));
+
+        // El primer tick es inmediato, sin esperar el intervalo completo
+        for _ in 0..50 {
+            if status_of(&db, &auction).await == AuctionStatus::Active {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        handle.abort();
+        assert_eq!(status_of(&db, &auction).await, AuctionStatus::Active);
+    }
+}
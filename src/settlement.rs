@@ -0,0 +1,315 @@
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use uuid::Uuid;
+
+use crate::domain::{AuctionStatus, BidStatus, ProxyBidStatus};
+use crate::models::auction::{
+    ActiveModel as AuctionActiveModel, Column as AuctionColumn, Entity as AuctionEntity, Model as AuctionModel,
+};
+use crate::models::auction_result::{
+    ActiveModel as AuctionResultActiveModel, Column as AuctionResultColumn, Entity as AuctionResultEntity,
+    Model as AuctionResultModel,
+};
+use crate::models::bid::{Column as BidColumn, Entity as BidEntity};
+use crate::models::proxy_bid::{Column as ProxyBidColumn, Entity as ProxyBidEntity};
+
+// Registra el resultado de una subasta cerrada: la puja más alta queda como "won"
+// (en empate gana la más antigua) y el resto de pujas activas o superadas como "lost". Si hay
+// precio de reserva y no se alcanzó, todas las pujas quedan como "lost".
+// Si la subasta ya tiene resultado se devuelve el existente sin modificar nada.
+pub async fn settle_auction<C: ConnectionTrait>(
+    db: &C,
+    auction: &AuctionModel,
+    closed_at: chrono::DateTime<chrono::Utc>,
+) -> Result<AuctionResultModel, DbErr> {
+    if let Some(existing) = AuctionResultEntity::find()
+        .filter(AuctionResultColumn::AuctionId.eq(auction.id))
+        .one(db)
+        .await?
+    {
+        return Ok(existing);
+    }
+
+    let top_bid = BidEntity::find()
+        .filter(BidColumn::AuctionId.eq(auction.id))
+        .filter(BidColumn::Status.eq(BidStatus::Active))
+        .order_by_desc(BidColumn::Amount)
+        .order_by_asc(BidColumn::CreatedAt)
+        .one(db)
+        .await?;
+
+    // Si la puja más alta no alcanza el precio de reserva la subasta cierra sin ganador
+    let reserve_met = match (&top_bid, auction.reserve_price) {
+        (Some(bid), Some(reserve)) => bid.amount >= reserve,
+        _ => true,
+    };
+    let winning_bid = top_bid.filter(|_| reserve_met);
+
+    BidEntity::update_many()
+        .col_expr(BidColumn::Status, Expr::value(BidStatus::Lost))
+        .filter(BidColumn::AuctionId.eq(auction.id))
+        .filter(BidColumn::Status.is_in([BidStatus::Active, BidStatus::Outbid]))
+        .exec(db)
+        .await?;
+
+    if let Some(winner) = &winning_bid {
+        BidEntity::update_many()
+            .col_expr(BidColumn::Status, Expr::value(BidStatus::Won))
+            .filter(BidColumn::Id.eq(winner.id))
+            .exec(db)
+            .await?;
+    }
+
+    let result = AuctionResultActiveModel {
+        id: Set(Uuid::new_v4()),
+        auction_id: Set(auction.id),
+        winning_bid_id: Set(winning_bid.as_ref().map(|b| b.id)),
+        winner_user_id: Set(winning_bid.as_ref().map(|b| b.user_id.clone())),
+        final_price: Set(winning_bid.as_ref().map(|b| b.amount)),
+        currency: Set(auction.currency),
+        closed_at: Set(closed_at),
+        reserve_met: Set(reserve_met),
+    };
+    let inserted = result.insert(db).await?;
+
+    match &inserted.winner_user_id {
+        Some(winner) => log::info!("Subasta {} cerrada - ganador: {}, precio final: {:?}",
+            auction.id, winner, inserted.final_price),
+        None if !inserted.reserve_met => log::info!("Subasta {} cerrada sin ganador: precio de reserva no alcanzado", auction.id),
+        None => log::info!("Subasta {} cerrada sin pujas ganadoras", auction.id),
+    }
+    Ok(inserted)
+}
+
+// Al cancelar una subasta ninguna puja puede ganar: las pujas vigentes (activas o
+// superadas) y las pujas automáticas activas quedan como "cancelled".
+// Devuelve la cantidad de pujas canceladas.
+pub async fn cancel_bids<C: ConnectionTrait>(db: &C, auction_id: Uuid) -> Result<u64, DbErr> {
+    let cancelled = BidEntity::update_many()
+        .col_expr(BidColumn::Status, Expr::value(BidStatus::Cancelled))
+        .filter(BidColumn::AuctionId.eq(auction_id))
+        .filter(BidColumn::Status.is_in([BidStatus::Active, BidStatus::Outbid]))
+        .exec(db)
+        .await?
+        .rows_affected;
+
+    ProxyBidEntity::update_many()
+        .col_expr(ProxyBidColumn::Status, Expr::value(ProxyBidStatus::Cancelled))
+        .filter(ProxyBidColumn::AuctionId.eq(auction_id))
+        .filter(ProxyBidColumn::Status.eq(ProxyBidStatus::Active))
+        .exec(db)
+        .await?;
+
+    log::info!("Subasta {} cancelada: {} pujas canceladas", auction_id, cancelled);
+    Ok(cancelled)
+}
+
+// Cierra una subasta activa (active -> completed) y registra su resultado en una
+// sola transacción. Devuelve None si la subasta no existe, fue eliminada o ya no
+// está activa.
+pub async fn complete_auction(
+    db: &DatabaseConnection,
+    auction_id: Uuid,
+    closed_at: chrono::DateTime<chrono::Utc>,
+) -> Result<Option<AuctionResultModel>, DbErr> {
+    let txn = db.begin().await?;
+
+    let Some(auction) = AuctionEntity::find_by_id(auction_id)
+        .filter(AuctionColumn::DeletedAt.is_null())
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+    else {
+        return Ok(None);
+    };
+    if auction.status != AuctionStatus::Active {
+        return Ok(None);
+    }
+
+    let version = auction.version;
+    let mut active: AuctionActiveModel = auction.into();
+    active.status = Set(AuctionStatus::Completed);
+    active.version = Set(version + 1);
+    let updated = active.update(&txn).await?;
+
+    let result = settle_auction(&txn, &updated, closed_at).await?;
+    txn.commit().await?;
+    Ok(Some(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::AuctionCurrency;
+    use crate::models::bid::{ActiveModel as BidActiveModel, Model as BidModel};
+    use crate::test_utils::setup_test_db;
+
+    async fn insert_auction(db: &DatabaseConnection, status: AuctionStatus) -> AuctionModel {
+        let now = chrono::Utc::now();
+        AuctionActiveModel {
+            id: Set(Uuid::new_v4()),
+            user_id: Set("seller".to_string()),
+            item_id: Set("item".to_string()),
+            title: Set("Test".to_string()),
+            description: Set(None),
+            category: Set("Electronics".to_string()),
+            start_time: Set(now - chrono::Duration::hours(2)),
+            end_time: Set(now - chrono::Duration::hours(1)),
+            base_price: Set(rust_decimal::Decimal::from(100)),
+            min_bid_increment: Set(rust_decimal::Decimal::from(10)),
+            highest_bid: Set(None),
+            status: Set(status),
+            currency: Set(AuctionCurrency::CLP),
+            reserve_price: Set(None),
+            extension_window_secs: Set(0),
+            extension_secs: Set(0),
+            max_extensions: Set(0),
+            extensions_count: Set(0),
+            deleted_at: Set(None),
+            version: Set(1),
+        }
+        .insert(db)
+        .await
+        .unwrap()
+    }
+
+    async fn insert_bid(db: &DatabaseConnection, auction: &AuctionModel, user: &str, amount: i64, minutes: i64) -> BidModel {
+        BidActiveModel {
+            id: Set(Uuid::new_v4()),
+            auction_id: Set(auction.id),
+            user_id: Set(user.to_string()),
+            amount: Set(rust_decimal::Decimal::from(amount)),
+            created_at: Set(auction.start_time + chrono::Duration::minutes(minutes)),
+            status: Set(BidStatus::Active),
+            retracted_at: Set(None),
+            retraction_reason: Set(None),
+        }
+        .insert(db)
+        .await
+        .unwrap()
+    }
+
+    async fn bid_status(db: &DatabaseConnection, bid: &BidModel) -> BidStatus {
+        BidEntity::find_by_id(bid.id).one(db).await.unwrap().unwrap().status
+    }
+
+    #[tokio::test]
+    async fn test_complete_auction_marks_winner_and_losers() {
+        let db = setup_test_db().await;
+        let auction = insert_auction(&db, AuctionStatus::Active).await;
+        let low = insert_bid(&db, &auction, "alice", 110, 1).await;
+        let high = insert_bid(&db, &auction, "bob", 130, 2).await;
+        let mid = insert_bid(&db, &auction, "carol", 120, 3).await;
+
+        let closed_at = chrono::Utc::now();
+        let result = complete_auction(&db, auction.id, closed_at).await.unwrap().unwrap();
+
+        assert_eq!(result.winning_bid_id, Some(high.id));
+        assert_eq!(result.winner_user_id.as_deref(), Some("bob"));
+        assert_eq!(result.final_price, Some(rust_decimal::Decimal::from(130)));
+        assert_eq!(result.currency, AuctionCurrency::CLP);
+        assert_eq!(bid_status(&db, &high).await, BidStatus::Won);
+        assert_eq!(bid_status(&db, &low).await, BidStatus::Lost);
+        assert_eq!(bid_status(&db, &mid).await, BidStatus::Lost);
+
+        let stored = AuctionEntity::find_by_id(auction.id).one(&db).await.unwrap().unwrap();
+        assert_eq!(stored.status, AuctionStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_complete_auction_tie_goes_to_earliest_bid() {
+        let db = setup_test_db().await;
+        let auction = insert_auction(&db, AuctionStatus::Active).await;
+        let late = insert_bid(&db, &auction, "late", 150, 5).await;
+        let early = insert_bid(&db, &auction, "early", 150, 1).await;
+
+        let result = complete_auction(&db, auction.id, chrono::Utc::now()).await.unwrap().unwrap();
+
+        assert_eq!(result.winning_bid_id, Some(early.id));
+        assert_eq!(bid_status(&db, &late).await, BidStatus::Lost);
+    }
+
+    #[tokio::test]
+    async fn test_complete_auction_without_bids_has_no_winner() {
+        let db = setup_test_db().await;
+        let auction = insert_auction(&db, AuctionStatus::Active).await;
+
+        let result = complete_auction(&db, auction.id, chrono::Utc::now()).await.unwrap().unwrap();
+
+        assert_eq!(result.winning_bid_id, None);
+        assert_eq!(result.winner_user_id, None);
+        assert_eq!(result.final_price, None);
+    }
+
+    #[tokio::test]
+    async fn test_complete_auction_reserve_not_met() {
+        let db = setup_test_db().await;
+        let auction = insert_auction(&db, AuctionStatus::Active).await;
+        let mut active: AuctionActiveModel = auction.clone().into();
+        active.reserve_price = Set(Some(rust_decimal::Decimal::from(200)));
+        let auction = active.update(&db).await.unwrap();
+        let bid = insert_bid(&db, &auction, "alice", 150, 1).await;
+
+        let result = complete_auction(&db, auction.id, chrono::Utc::now()).await.unwrap().unwrap();
+
+        assert!(!result.reserve_met);
+        assert_eq!(result.winning_bid_id, None);
+        assert_eq!(result.final_price, None);
+        assert_eq!(bid_status(&db, &bid).await, BidStatus::Lost);
+    }
+
+    #[tokio::test]
+    async fn test_complete_auction_reserve_met() {
+        let db = setup_test_db().await;
+        let auction = insert_auction(&db, AuctionStatus::Active).await;
+        let mut active: AuctionActiveModel = auction.clone().into();
+        active.reserve_price = Set(Some(rust_decimal::Decimal::from(200)));
+        let auction = active.update(&db).await.unwrap();
+        let bid = insert_bid(&db, &auction, "alice", 200, 1).await;
+
+        let result = complete_auction(&db, auction.id, chrono::Utc::now()).await.unwrap().unwrap();
+
+        assert!(result.reserve_met);
+        assert_eq!(result.winning_bid_id, Some(bid.id));
+        assert_eq!(bid_status(&db, &bid).await, BidStatus::Won);
+    }
+
+    #[tokio::test]
+    async fn test_complete_auction_ignores_non_active() {
+        let db = setup_test_db().await;
+        let auction = insert_auction(&db, AuctionStatus::Cancelled).await;
+
+        let result = complete_auction(&db, auction.id, chrono::Utc::now()).await.unwrap();
+        assert!(result.is_none());
+        assert!(AuctionResultEntity::find().one(&db).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_bids_cancels_outstanding_bids() {
+        let db = setup_test_db().await;
+        let auction = insert_auction(&db, AuctionStatus::Cancelled).await;
+        let outbid = insert_bid(&db, &auction, "alice", 110, 1).await;
+        let leading = insert_bid(&db, &auction, "bob", 120, 2).await;
+        let mut active: BidActiveModel = outbid.clone().into();
+        active.status = Set(BidStatus::Outbid);
+        active.update(&db).await.unwrap();
+
+        assert_eq!(cancel_bids(&db, auction.id).await.unwrap(), 2);
+        assert_eq!(bid_status(&db, &outbid).await, BidStatus::Cancelled);
+        assert_eq!(bid_status(&db, &leading).await, BidStatus::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_settle_auction_is_idempotent() {
+        let db = setup_test_db().await;
+        let auction = insert_auction(&db, AuctionStatus::Completed).await;
+        insert_bid(&db, &auction, "alice", 110, 1).await;
+
+        let first = settle_auction(&db, &auction, chrono::Utc::now()).await.unwrap();
+        let second = settle_auction(&db, &auction, chrono::Utc::now()).await.unwrap();
+        assert_eq!(first, second);
+    }
+}
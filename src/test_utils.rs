@@ -0,0 +1,154 @@
+// Utilidades compartidas por los tests de los distintos módulos
+use std::sync::Mutex;
+
+use sea_orm::{ConnectionTrait, Database, DatabaseConnection, Statement};
+
+use crate::scheduler::Clock;
+
+// Reloj controlado manualmente por el test
+pub struct FakeClock(Mutex<chrono::DateTime<chrono::Utc>>);
+
+impl FakeClock {
+    pub fn new(now: chrono::DateTime<chrono::Utc>) -> Self {
+        FakeClock(Mutex::new(now))
+    }
+
+    pub fn advance(&self, by: chrono::Duration) {
+        let mut now = self.0.lock().unwrap();
+        *now += by;
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> chrono::DateTime<chrono::Utc> {
+        *self.0.lock().unwrap()
+    }
+}
+
+// Base de datos SQLite en memoria con el mismo esquema que las migraciones.
+// Los montos son REAL: en SQLite una columna NUMERIC guarda los montos enteros
+// como INTEGER (y los demás también como REAL), y SeaORM solo sabe leer un
+// Decimal de SQLite desde un REAL. Las respuestas formatean los montos con la
+// escala de la moneda, así que los tests comparan el texto exacto.
+pub async fn setup_test_db() -> DatabaseConnection {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+
+    // Crear tabla auction con status y currency
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        r#"
+CREATE TABLE auction (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL,
+    item_id TEXT NOT NULL,
+    title TEXT NOT NULL,
+    description TEXT,
+    category TEXT NOT NULL,
+    start_time TEXT NOT NULL,
+    end_time TEXT NOT NULL,
+    base_price REAL NOT NULL,
+    min_bid_increment REAL NOT NULL,
+    highest_bid REAL,
+    status TEXT NOT NULL DEFAULT 'pending',
+    currency TEXT NOT NULL DEFAULT 'USD',
+    reserve_price REAL,
+    extension_window_secs INTEGER NOT NULL DEFAULT 0,
+    extension_secs INTEGER NOT NULL DEFAULT 0,
+    max_extensions INTEGER NOT NULL DEFAULT 0,
+    extensions_count INTEGER NOT NULL DEFAULT 0,
+    deleted_at TEXT,
+    version INTEGER NOT NULL DEFAULT 1
+);
+        "#.to_owned(),
+    )).await.unwrap();
+
+    // Crear tabla bid
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        r#"
+CREATE TABLE bid (
+    id TEXT PRIMARY KEY,
+    auction_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    amount REAL NOT NULL,
+    created_at TEXT NOT NULL,
+    status TEXT NOT NULL,
+    retracted_at TEXT,
+    retraction_reason TEXT,
+    FOREIGN KEY (auction_id) REFERENCES auction (id)
+);
+        "#.to_owned(),
+    )).await.unwrap();
+
+    // Crear tabla auction_result
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        r#"
+CREATE TABLE auction_result (
+    id TEXT PRIMARY KEY,
+    auction_id TEXT NOT NULL UNIQUE,
+    winning_bid_id TEXT,
+    winner_user_id TEXT,
+    final_price REAL,
+    currency TEXT NOT NULL,
+    closed_at TEXT NOT NULL,
+    reserve_met BOOLEAN NOT NULL DEFAULT 1,
+    FOREIGN KEY (auction_id) REFERENCES auction (id),
+    FOREIGN KEY (winning_bid_id) REFERENCES bid (id)
+);
+        "#.to_owned(),
+    )).await.unwrap();
+
+    // Crear tabla proxy_bid
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        r#"
+CREATE TABLE proxy_bid (
+    id TEXT PRIMARY KEY,
+    auction_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    max_amount REAL NOT NULL,
+    created_at TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'active' CHECK (status IN ('active', 'exhausted', 'cancelled', 'retracted')),
+    UNIQUE (auction_id, user_id),
+    FOREIGN KEY (auction_id) REFERENCES auction (id)
+);
+        "#.to_owned(),
+    )).await.unwrap();
+
+    // Crear tabla auction_closure
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        r#"
+CREATE TABLE auction_closure (
+    id TEXT PRIMARY KEY,
+    auction_id TEXT NOT NULL UNIQUE,
+    action TEXT NOT NULL,
+    actor_id TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    closed_at TEXT NOT NULL,
+    FOREIGN KEY (auction_id) REFERENCES auction (id)
+);
+        "#.to_owned(),
+    )).await.unwrap();
+
+    // Crear tabla idempotency_key
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        r#"
+CREATE TABLE idempotency_key (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL,
+    operation TEXT NOT NULL,
+    key TEXT NOT NULL,
+    request BLOB NOT NULL,
+    response BLOB NOT NULL,
+    created_at TEXT NOT NULL,
+    expires_at TEXT NOT NULL,
+    UNIQUE (user_id, operation, key)
+);
+        "#.to_owned(),
+    )).await.unwrap();
+
+    db
+}
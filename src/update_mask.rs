@@ -0,0 +1,198 @@
+use crate::error::AppError;
+use crate::grpc_server::auction::UpdateAuctionRequest;
+
+// Campos que UpdateAuction nunca modifica: identidad de la subasta o valores
+// que mantiene el servidor
+const IMMUTABLE_FIELDS: [&str; 10] = [
+    "id",
+    "user_id",
+    "item_id",
+    "highest_bid",
+    "extensions_count",
+    "version",
+    "deleted_at",
+    "bids",
+    "has_reserve",
+    "reserve_met",
+];
+
+// Campos editables de una subasta. Los tres valores del cierre suave se
+// validan juntos, así que cualquiera de sus rutas los reemplaza a los tres.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateField {
+    Title,
+    Description,
+    Category,
+    StartTime,
+    EndTime,
+    BasePrice,
+    MinBidIncrement,
+    ReservePrice,
+    Currency,
+    Status,
+    SoftClose,
+}
+
+impl UpdateField {
+    pub fn parse(path: &str) -> Result<Self, AppError> {
+        match path {
+            "title" => Ok(UpdateField::Title),
+            "description" => Ok(UpdateField::Description),
+            "category" => Ok(UpdateField::Category),
+            "start_time" => Ok(UpdateField::StartTime),
+            "end_time" => Ok(UpdateField::EndTime),
+            "base_price" => Ok(UpdateField::BasePrice),
+            "min_bid_increment" => Ok(UpdateField::MinBidIncrement),
+            "reserve_price" => Ok(UpdateField::ReservePrice),
+            "currency" | "currency_value" => Ok(UpdateField::Currency),
+            "status" | "status_value" => Ok(UpdateField::Status),
+            "extension_window_seconds" | "extension_seconds" | "max_extensions" => Ok(UpdateField::SoftClose),
+            _ => match IMMUTABLE_FIELDS.iter().find(|f| **f == path) {
+                Some(field) => Err(AppError::ImmutableField { field }),
+                None => Err(AppError::UnknownUpdateField { path: path.to_string() }),
+            },
+        }
+    }
+
+    pub fn path(&self) -> &'static str {
+        match self {
+            UpdateField::Title => "title",
+            UpdateField::Description => "description",
+            UpdateField::Category => "category",
+            UpdateField::StartTime => "start_time",
+            UpdateField::EndTime => "end_time",
+            UpdateField::BasePrice => "base_price",
+            UpdateField::MinBidIncrement => "min_bid_increment",
+            UpdateField::ReservePrice => "reserve_price",
+            UpdateField::Currency => "currency",
+            UpdateField::Status => "status",
+            UpdateField::SoftClose => "extension_window_seconds",
+        }
+    }
+
+    // Precios y fechas solo se pueden editar mientras la subasta está pendiente
+    pub fn pending_only(&self) -> bool {
+        matches!(
+            self,
+            UpdateField::StartTime
+                | UpdateField::EndTime
+                | UpdateField::BasePrice
+                | UpdateField::MinBidIncrement
+                | UpdateField::ReservePrice
+                | UpdateField::Currency
+        )
+    }
+}
+
+// Campos que modifica la solicitud, sin repetir:
+// - con update_mask, exactamente las rutas indicadas; un valor vacío es intencional
+//   (por ejemplo, description vacía la borra)
+// - sin update_mask, los campos con valor, como hacían los clientes anteriores
+pub fn requested_fields(req: &UpdateAuctionRequest) -> Result<Vec<UpdateField>, AppError> {
+    let mut fields = Vec::new();
+    match &req.update_mask {
+        Some(mask) if !mask.paths.is_empty() => {
+            for path in &mask.paths {
+                let field = UpdateField::parse(path.trim())?;
+                if !fields.contains(&field) {
+                    fields.push(field);
+                }
+            }
+        },
+        _ => {
+            if !req.highest_bid.is_empty() {
+                return Err(AppError::ImmutableField { field: "highest_bid" });
+            }
+            let provided = [
+                (UpdateField::Title, !req.title.is_empty()),
+                (UpdateField::Description, !req.description.is_empty()),
+                (UpdateField::Category, !req.category.is_empty()),
+                (UpdateField::StartTime, req.start_time.is_some()),
+                (UpdateField::EndTime, req.end_time.is_some()),
+                (UpdateField::BasePrice, !req.base_price.is_empty()),
+                (UpdateField::MinBidIncrement, !req.min_bid_increment.is_empty()),
+                (UpdateField::ReservePrice, !req.reserve_price.is_empty()),
+                (UpdateField::Currency, !req.currency.is_empty() || req.currency_value != 0),
+                (UpdateField::Status, !req.status.is_empty() || req.status_value != 0),
+                (
+                    UpdateField::SoftClose,
+                    req.extension_window_seconds != 0 || req.extension_seconds != 0 || req.max_extensions != 0,
+                ),
+            ];
+            fields.extend(provided.into_iter().filter(|(_, present)| *present).map(|(field, _)| field));
+        },
+    }
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn masked(paths: &[&str]) -> UpdateAuctionRequest {
+        UpdateAuctionRequest {
+            update_mask: Some(prost_types::FieldMask { paths: paths.iter().map(|p| p.to_string()).collect() }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_mask_selects_exact_fields() {
+        let fields = requested_fields(&masked(&["description", "max_extensions", "extension_seconds"])).unwrap();
+        assert_eq!(fields, vec![UpdateField::Description, UpdateField::SoftClose]);
+    }
+
+    #[test]
+    fn test_mask_rejects_immutable_and_unknown_paths() {
+        for path in ["highest_bid", "user_id", "id"] {
+            let err = requested_fields(&masked(&["title", path])).unwrap_err();
+            assert!(matches!(err, AppError::ImmutableField { field } if field == path), "{}", path);
+        }
+        let err = requested_fields(&masked(&["titulo"])).unwrap_err();
+        assert!(matches!(err, AppError::UnknownUpdateField { path } if path == "titulo"));
+    }
+
+    #[test]
+    fn test_without_mask_uses_present_values() {
+        let req = UpdateAuctionRequest {
+            title: "Nuevo".to_string(),
+            status: "active".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(requested_fields(&req).unwrap(), vec![UpdateField::Title, UpdateField::Status]);
+
+        // El valor tipado cuenta igual que el texto
+        let req = UpdateAuctionRequest { currency_value: 3, ..Default::default() };
+        assert_eq!(requested_fields(&req).unwrap(), vec![UpdateField::Currency]);
+        assert_eq!(requested_fields(&masked(&["status_value"])).unwrap(), vec![UpdateField::Status]);
+
+        // highest_bid solo lo escribe el servidor
+        let req = UpdateAuctionRequest { highest_bid: "500".to_string(), ..Default::default() };
+        assert!(matches!(requested_fields(&req), Err(AppError::ImmutableField { field: "highest_bid" })));
+    }
+
+    #[test]
+    fn test_prices_and_times_are_pending_only() {
+        let pending_only: Vec<&str> = [
+            UpdateField::Title,
+            UpdateField::Description,
+            UpdateField::Category,
+            UpdateField::StartTime,
+            UpdateField::EndTime,
+            UpdateField::BasePrice,
+            UpdateField::MinBidIncrement,
+            UpdateField::ReservePrice,
+            UpdateField::Currency,
+            UpdateField::Status,
+            UpdateField::SoftClose,
+        ]
+        .into_iter()
+        .filter(UpdateField::pending_only)
+        .map(|f| f.path())
+        .collect();
+        assert_eq!(
+            pending_only,
+            vec!["start_time", "end_time", "base_price", "min_bid_increment", "reserve_price", "currency"]
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use rust_decimal::Decimal;
+
+use crate::error::AppError;
+use crate::domain::AuctionCurrency;
+use crate::models::auction::ActiveModel as AuctionActiveModel;
+
+// Límites de longitud en caracteres
+pub const MAX_TITLE_LEN: usize = 200;
+pub const MAX_DESCRIPTION_LEN: usize = 5000;
+
+// Decimales permitidos en los montos de cada moneda: el peso chileno no usa
+// centavos, el resto admite dos decimales
+pub fn currency_scale(currency: &AuctionCurrency) -> u32 {
+    match currency {
+        AuctionCurrency::CLP => 0,
+        AuctionCurrency::USD
+        | AuctionCurrency::EUR
+        | AuctionCurrency::ARS
+        | AuctionCurrency::BRL
+        | AuctionCurrency::MXN => 2,
+    }
+}
+
+// Valida una subasta tal como quedaría guardada. CreateAuction la llama con el
+// modelo nuevo y UpdateAuction con el modelo guardado más los cambios, así las
+// reglas que relacionan campos (fechas, reserva, decimales por moneda) se
+// comprueban aunque la solicitud cambie solo uno de ellos.
+// Que una fecha no esté en el pasado solo se exige para las fechas que la
+// solicitud establece: una subasta en curso ya empezó.
+pub fn validate_auction(auction: &AuctionActiveModel, now: chrono::DateTime<chrono::Utc>) -> Result<(), AppError> {
+    validate_text(auction)?;
+    validate_schedule(auction, now)?;
+    validate_prices(auction)?;
+    validate_soft_close(
+        *auction.extension_window_secs.as_ref(),
+        *auction.extension_secs.as_ref(),
+        *auction.max_extensions.as_ref(),
+    )
+}
+
+fn validate_text(auction: &AuctionActiveModel) -> Result<(), AppError> {
+    let title = auction.title.as_ref();
+    if title.trim().is_empty() {
+        return Err(AppError::Required { field: "title" });
+    }
+    if title.chars().count() > MAX_TITLE_LEN {
+        return Err(AppError::TooLong { field: "title", max: MAX_TITLE_LEN });
+    }
+    if let Some(description) = auction.description.as_ref() {
+        if description.chars().count() > MAX_DESCRIPTION_LEN {
+            return Err(AppError::TooLong { field: "description", max: MAX_DESCRIPTION_LEN });
+        }
+    }
+    if auction.category.as_ref().trim().is_empty() {
+        return Err(AppError::Required { field: "category" });
+    }
+    Ok(())
+}
+
+fn validate_schedule(auction: &AuctionActiveModel, now: chrono::DateTime<chrono::Utc>) -> Result<(), AppError> {
+    if auction.start_time.as_ref() >= auction.end_time.as_ref() {
+        return Err(AppError::InvalidDateRange);
+    }
+    if auction.start_time.is_set() && *auction.start_time.as_ref() < now {
+        return Err(AppError::StartTimeInPast);
+    }
+    if auction.end_time.is_set() && *auction.end_time.as_ref() <= now {
+        return Err(AppError::EndTimeInPast);
+    }
+    Ok(())
+}
+
+fn validate_prices(auction: &AuctionActiveModel) -> Result<(), AppError> {
+    let currency = auction.currency.as_ref();
+    let base_price = *auction.base_price.as_ref();
+    validate_amount(base_price, "base_price", currency)?;
+    validate_amount(*auction.min_bid_increment.as_ref(), "min_bid_increment", currency)?;
+    if let Some(reserve) = *auction.reserve_price.as_ref() {
+        validate_amount(reserve, "reserve_price", currency)?;
+        if reserve < base_price {
+            return Err(AppError::ReserveBelowBasePrice);
+        }
+    }
+    Ok(())
+}
+
+// Un monto debe ser positivo y no tener más decimales de los que admite la moneda
+fn validate_amount(amount: Decimal, field: &'static str, currency: &AuctionCurrency) -> Result<(), AppError> {
+    if amount <= Decimal::ZERO {
+        return Err(AppError::NotPositive { field });
+    }
+    let max_scale = currency_scale(currency);
+    if amount.normalize().scale() > max_scale {
+        return Err(AppError::TooManyDecimals { field, max_scale });
+    }
+    Ok(())
+}
+
+// Valida la configuración de cierre suave: todo en cero la desactiva, de lo
+// contrario los tres valores deben ser positivos
+pub fn validate_soft_close(window_secs: i32, extension_secs: i32, max_extensions: i32) -> Result<(), AppError> {
+    if window_secs == 0 && extension_secs == 0 && max_extensions == 0 {
+        return Ok(());
+    }
+    if window_secs <= 0 || extension_secs <= 0 || max_extensions <= 0 {
+        return Err(AppError::InvalidSoftClose);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::auction::Model as AuctionModel;
+    use crate::domain::AuctionStatus;
+    use sea_orm::Set;
+
+    fn now() -> chrono::DateTime<chrono::Utc> {
+        chrono::NaiveDate::from_ymd_opt(2030, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc()
+    }
+
+    // Subasta válida guardada: el punto de partida de cada caso
+    fn stored() -> AuctionModel {
+        AuctionModel {
+            id: uuid::Uuid::new_v4(),
+            user_id: "seller".to_string(),
+            item_id: "item".to_string(),
+            title: "Guitarra".to_string(),
+            description: Some("Usada".to_string()),
+            category: "Music".to_string(),
+            start_time: now() + chrono::Duration::hours(1),
+            end_time: now() + chrono::Duration::hours(2),
+            base_price: Decimal::new(10000, 2),
+            min_bid_increment: Decimal::new(500, 2),
+            highest_bid: None,
+            status: AuctionStatus::Pending,
+            currency: AuctionCurrency::USD,
+            reserve_price: None,
+            extension_window_secs: 0,
+            extension_secs: 0,
+            max_extensions: 0,
+            extensions_count: 0,
+            deleted_at: None,
+            version: 1,
+        }
+    }
+
+    // Nombre del caso, cambio sobre la subasta guardada y motivo esperado ("OK" si es válida)
+    type Case = (&'static str, Box<dyn FnOnce(&mut AuctionActiveModel)>, &'static str);
+
+    fn check(patch: impl FnOnce(&mut AuctionActiveModel)) -> Result<(), AppError> {
+        let mut active: AuctionActiveModel = stored().into();
+        patch(&mut active);
+        validate_auction(&active, now())
+    }
+
+    fn reason(result: Result<(), AppError>) -> &'static str {
+        result.err().map(|e| e.reason()).unwrap_or("OK")
+    }
+
+    #[test]
+    fn test_stored_auction_is_valid() {
+        assert!(check(|_| {}).is_ok());
+    }
+
+    #[test]
+    fn test_date_rules() {
+        let cases: Vec<Case> = vec![
+            ("fin antes del inicio guardado", Box::new(|a| a.end_time = Set(now() + chrono::Duration::minutes(30))), "INVALID_DATE_RANGE"),
+            ("inicio después del fin guardado", Box::new(|a| a.start_time = Set(now() + chrono::Duration::hours(3))), "INVALID_DATE_RANGE"),
+            ("fin igual al inicio", Box::new(|a| a.end_time = Set(now() + chrono::Duration::hours(1))), "INVALID_DATE_RANGE"),
+            ("inicio en el pasado", Box::new(|a| a.start_time = Set(now() - chrono::Duration::minutes(1))), "START_TIME_IN_PAST"),
+            ("inicio ahora", Box::new(|a| a.start_time = Set(now())), "OK"),
+            ("fin en el pasado", Box::new(|a| {
+                a.start_time = sea_orm::ActiveValue::Unchanged(now() - chrono::Duration::hours(2));
+                a.end_time = Set(now() - chrono::Duration::minutes(1));
+            }), "END_TIME_IN_PAST"),
+            ("fin más tarde", Box::new(|a| a.end_time = Set(now() + chrono::Duration::days(7))), "OK"),
+        ];
+        for (name, patch, expected) in cases {
+            assert_eq!(reason(check(patch)), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_running_auction_keeps_past_start_time() {
+        // Una subasta activa ya empezó: editar otro campo no revalida start_time
+        let mut model = stored();
+        model.start_time = now() - chrono::Duration::hours(1);
+        let mut active: AuctionActiveModel = model.into();
+        active.title = Set("Guitarra eléctrica".to_string());
+        assert!(validate_auction(&active, now()).is_ok());
+    }
+
+    #[test]
+    fn test_price_rules() {
+        let cases: Vec<Case> = vec![
+            ("precio base cero", Box::new(|a| a.base_price = Set(Decimal::ZERO)), "NOT_POSITIVE"),
+            ("precio base negativo", Box::new(|a| a.base_price = Set(Decimal::new(-1, 0))), "NOT_POSITIVE"),
+            ("incremento cero", Box::new(|a| a.min_bid_increment = Set(Decimal::ZERO)), "NOT_POSITIVE"),
+            ("reserva negativa", Box::new(|a| a.reserve_price = Set(Some(Decimal::new(-5, 0)))), "NOT_POSITIVE"),
+            ("reserva bajo el precio base guardado", Box::new(|a| a.reserve_price = Set(Some(Decimal::new(50, 0)))), "RESERVE_BELOW_BASE_PRICE"),
+            ("precio base sobre la reserva guardada", Box::new(|a| {
+                a.reserve_price = sea_orm::ActiveValue::Unchanged(Some(Decimal::new(150, 0)));
+                a.base_price = Set(Decimal::new(200, 0));
+            }), "RESERVE_BELOW_BASE_PRICE"),
+            ("reserva igual al precio base", Box::new(|a| a.reserve_price = Set(Some(Decimal::new(100, 0)))), "OK"),
+        ];
+        for (name, patch, expected) in cases {
+            assert_eq!(reason(check(patch)), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_decimal_scale_per_currency() {
+        let cases: Vec<(&str, AuctionCurrency, Decimal, &str)> = vec![
+            ("USD con centavos", AuctionCurrency::USD, Decimal::new(10099, 2), "OK"),
+            ("USD con ceros a la derecha", AuctionCurrency::USD, Decimal::new(1001000, 4), "OK"),
+            ("USD con tres decimales", AuctionCurrency::USD, Decimal::new(100999, 3), "INVALID_SCALE"),
+            ("CLP entero", AuctionCurrency::CLP, Decimal::new(15000, 0), "OK"),
+            ("CLP con .00", AuctionCurrency::CLP, Decimal::new(1500000, 2), "OK"),
+            ("CLP con centavos", AuctionCurrency::CLP, Decimal::new(1500050, 2), "INVALID_SCALE"),
+        ];
+        for (name, currency, base_price, expected) in cases {
+            let result = check(|a| {
+                a.currency = Set(currency);
+                a.base_price = Set(base_price);
+                a.min_bid_increment = Set(Decimal::ONE);
+            });
+            assert_eq!(reason(result), expected, "{}", name);
+        }
+
+        // Cambiar solo la moneda revalida los montos guardados
+        assert_eq!(
+            reason(check(|a| {
+                a.min_bid_increment = sea_orm::ActiveValue::Unchanged(Decimal::new(550, 2));
+                a.currency = Set(AuctionCurrency::CLP);
+            })),
+            "INVALID_SCALE"
+        );
+        assert_eq!(reason(AuctionCurrency::from_str("JPY").map(|_| ())), "INVALID_CURRENCY");
+    }
+
+    #[test]
+    fn test_text_rules() {
+        let cases: Vec<Case> = vec![
+            ("título vacío", Box::new(|a| a.title = Set("   ".to_string())), "FIELD_REQUIRED"),
+            ("título en el límite", Box::new(|a| a.title = Set("ñ".repeat(MAX_TITLE_LEN))), "OK"),
+            ("título largo", Box::new(|a| a.title = Set("x".repeat(MAX_TITLE_LEN + 1))), "TOO_LONG"),
+            ("descripción borrada", Box::new(|a| a.description = Set(None)), "OK"),
+            ("descripción larga", Box::new(|a| a.description = Set(Some("x".repeat(MAX_DESCRIPTION_LEN + 1)))), "TOO_LONG"),
+            ("categoría vacía", Box::new(|a| a.category = Set(String::new())), "FIELD_REQUIRED"),
+        ];
+        for (name, patch, expected) in cases {
+            assert_eq!(reason(check(patch)), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_soft_close_rules() {
+        assert!(validate_soft_close(0, 0, 0).is_ok());
+        assert!(validate_soft_close(60, 120, 3).is_ok());
+        assert!(validate_soft_close(60, 0, 3).is_err());
+        assert!(validate_soft_close(-1, 120, 3).is_err());
+        assert_eq!(reason(check(|a| a.extension_secs = Set(60))), "INVALID_SOFT_CLOSE");
+    }
+}